@@ -0,0 +1,108 @@
+use domain_types::types::{Connectors, Proxy};
+use serde::Deserialize;
+use std::path::PathBuf;
+
+/// Environment variable selecting which configuration file is loaded
+pub const RUN_ENV: &str = "RUN_ENV";
+/// Environment variable overriding the directory the configuration files are read from
+pub const CONFIG_DIR: &str = "CONFIG_DIR";
+/// Prefix of the environment variables overriding individual settings, e.g. `CS__SERVER__PORT`
+const ENV_PREFIX: &str = "CS";
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct Settings {
+    pub server: Server,
+    #[serde(default)]
+    pub proxy: Proxy,
+    pub connectors: Connectors,
+    #[serde(default)]
+    pub log: Log,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(default)]
+pub struct Log {
+    /// Filter directives, e.g. `info` or `info,external_services=debug`
+    pub level: String,
+    pub format: LogFormat,
+}
+
+impl Default for Log {
+    fn default() -> Self {
+        Self {
+            level: "info".to_string(),
+            format: LogFormat::default(),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LogFormat {
+    /// One JSON object per line, for log collectors
+    Json,
+    /// Multi-line human readable output, for local development
+    #[default]
+    Pretty,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct Server {
+    pub host: String,
+    pub port: u16,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Env {
+    #[default]
+    Development,
+    Sandbox,
+    Production,
+}
+
+impl Env {
+    pub fn current_env() -> Self {
+        match std::env::var(RUN_ENV).as_deref() {
+            Ok("production") => Self::Production,
+            Ok("sandbox") => Self::Sandbox,
+            _ => Self::Development,
+        }
+    }
+
+    pub const fn config_path(self) -> &'static str {
+        match self {
+            Self::Development => "development.toml",
+            Self::Sandbox => "sandbox.toml",
+            Self::Production => "production.toml",
+        }
+    }
+}
+
+impl Settings {
+    pub fn new() -> Result<Self, config::ConfigError> {
+        Self::with_config_path(Env::current_env(), None)
+    }
+
+    pub fn with_config_path(
+        environment: Env,
+        config_path: Option<PathBuf>,
+    ) -> Result<Self, config::ConfigError> {
+        let config_path = config_path.unwrap_or_else(|| {
+            let config_directory =
+                std::env::var(CONFIG_DIR).unwrap_or_else(|_| "config".to_string());
+            PathBuf::from(config_directory).join(environment.config_path())
+        });
+
+        config::Config::builder()
+            .add_source(config::File::from(config_path).required(true))
+            .add_source(
+                config::Environment::with_prefix(ENV_PREFIX)
+                    .try_parsing(true)
+                    .separator("__")
+                    .list_separator(",")
+                    .with_list_parse_key("proxy.bypass_proxy_urls"),
+            )
+            .build()?
+            .try_deserialize()
+    }
+}
@@ -1,13 +1,18 @@
+pub mod configs;
 pub mod http_utils;
+pub mod logger;
 pub mod routes;
 pub mod services;
 pub mod state;
 pub mod types;
 
 use actix_web::{web, App, HttpResponse, HttpServer};
-use state::AppState;
+use configs::Settings;
+use state::{AppConfig, AppState};
 
-pub fn mk_app() -> App<
+pub fn mk_app(
+    app_state: AppState,
+) -> App<
     impl actix_web::dev::ServiceFactory<
         actix_web::dev::ServiceRequest,
         Config = (),
@@ -16,27 +21,32 @@ pub fn mk_app() -> App<
         InitError = (),
     >,
 > {
-    let app_state = AppState::new();
-
     App::new()
         // .service(routes::Vehicles::server(app_state.clone()))
         .service(routes::Authorize::server(app_state.clone()))
+        .service(routes::Payments::server(app_state.clone()))
+        .service(routes::Capture::server(app_state.clone()))
+        .service(routes::Void::server(app_state.clone()))
+        .service(routes::Refunds::server(app_state.clone()))
         .route("/health", web::get().to(health_check))
 }
 
 pub async fn start_application() -> std::io::Result<()> {
-    env_logger::init();
-
-    println!("Starting Vehicle Management API at http://localhost:5000");
-    println!("Flow: vehicle_management");
-    println!("Available endpoints:");
-    println!("  POST /vehicles/v1 - Create vehicle (with full server_wrap)");
-    println!("  POST /authorize/v1 - Create authorize (with full server_wrap)");
-
-    println!("  GET  /health      - Health check");
-
-    HttpServer::new(|| mk_app())
-        .bind("127.0.0.1:5000")?
+    let settings = Settings::new().map_err(|err| {
+        std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            format!("Failed to load configuration: {err}"),
+        )
+    })?;
+    logger::setup(&settings.log)
+        .map_err(|err| std::io::Error::other(format!("Failed to set up logging: {err}")))?;
+    let app_state = AppState::new(AppConfig::from(settings));
+    let server = app_state.conf().server.clone();
+
+    tracing::info!(host = %server.host, port = server.port, "starting server");
+
+    HttpServer::new(move || mk_app(app_state.clone()))
+        .bind((server.host.as_str(), server.port))?
         .run()
         .await
 }
@@ -0,0 +1,17 @@
+use crate::configs::{Log, LogFormat};
+use tracing_subscriber::{fmt, EnvFilter};
+
+/// Installs the global subscriber, `RUST_LOG` takes precedence over the configured level
+pub fn setup(config: &Log) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    let filter =
+        EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new(&config.level));
+    let subscriber = fmt().with_env_filter(filter);
+    match config.format {
+        LogFormat::Json => subscriber
+            .json()
+            .with_current_span(true)
+            .with_span_list(true)
+            .try_init(),
+        LogFormat::Pretty => subscriber.pretty().try_init(),
+    }
+}
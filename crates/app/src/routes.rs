@@ -1,4 +1,6 @@
 pub mod payments;
+pub mod refunds;
 // pub mod vehicles;
-pub use payments::Authorize;
+pub use payments::{Authorize, Capture, Payments, Void};
+pub use refunds::Refunds;
 // pub use vehicles::Vehicles;
@@ -1,9 +1,16 @@
-use crate::services::api::server_wrap;
+use crate::services::api::connector_flow_wrap;
 use crate::state::AppState;
-use crate::types::{ApiEventMetric, AuthorizeFlow, ReqState, SessionState};
+use crate::types::{ApiEventMetric, AuthorizeFlow, CaptureFlow, PaymentSyncFlow, VoidFlow};
 use actix_web::{web, HttpRequest, Responder, Scope};
-use grpc::types::PaymentServiceAuthorizeRequest;
-use payment::core::{payment_authorize, PaymentAuthrorizeResponse};
+use grpc::types::{
+    PaymentServiceAuthorizeRequest, PaymentServiceCaptureRequest, PaymentServiceGetRequest,
+    PaymentServiceVoidRequest,
+};
+use payment::core::{
+    payment_authorize, payment_capture, payment_sync, payment_void, PaymentAuthrorizeResponse,
+    PaymentCaptureResponse, PaymentSyncResponse, PaymentVoidResponse,
+};
+use serde::Deserialize;
 
 // Implement ApiEventMetric for the app crate types
 impl ApiEventMetric for PaymentServiceAuthorizeRequest {
@@ -12,41 +19,129 @@ impl ApiEventMetric for PaymentServiceAuthorizeRequest {
     }
 }
 
+impl ApiEventMetric for PaymentServiceGetRequest {
+    fn event_type(&self) -> &'static str {
+        "sync_request"
+    }
+}
+
+impl ApiEventMetric for PaymentServiceCaptureRequest {
+    fn event_type(&self) -> &'static str {
+        "capture_request"
+    }
+}
+
+impl ApiEventMetric for PaymentServiceVoidRequest {
+    fn event_type(&self) -> &'static str {
+        "void_request"
+    }
+}
+
 impl ApiEventMetric for PaymentAuthrorizeResponse {
     fn event_type(&self) -> &'static str {
         "authorize_response"
     }
 }
 
+impl ApiEventMetric for PaymentSyncResponse {
+    fn event_type(&self) -> &'static str {
+        "sync_response"
+    }
+}
+
+impl ApiEventMetric for PaymentCaptureResponse {
+    fn event_type(&self) -> &'static str {
+        "capture_response"
+    }
+}
+
+impl ApiEventMetric for PaymentVoidResponse {
+    fn event_type(&self) -> &'static str {
+        "void_response"
+    }
+}
+
 pub async fn payment_authorize_request(
     state: web::Data<AppState>,
     request: HttpRequest,
     payload: web::Json<PaymentServiceAuthorizeRequest>,
 ) -> impl Responder {
-    let flow = AuthorizeFlow;
-    let app_state = state.get_ref().clone();
-    // let _ = Payments::new();
-    Box::pin(server_wrap(
-        flow,
+    connector_flow_wrap(
+        AuthorizeFlow,
         state,
         &request,
         payload.into_inner(),
-        move |_session_state: SessionState,
-              _user_data: SessionState,
-              request: PaymentServiceAuthorizeRequest,
-              req_state: ReqState| {
-            let app_state = app_state.clone();
-            async move {
-                println!(
-                    "Request ID: {}, Flow: {}",
-                    req_state.request_id, req_state.flow_name
-                );
-
-                // Use the new authorize function which returns CustomResult
-                payment_authorize(request).await
-            }
+        |payment, connector, connector_auth_type, request| async move {
+            payment_authorize(&payment, connector, connector_auth_type, request).await
         },
-    ))
+    )
+    .await
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PaymentSyncQuery {
+    pub request_ref_id: Option<String>,
+    pub encoded_data: Option<String>,
+}
+
+pub async fn payment_sync_request(
+    state: web::Data<AppState>,
+    request: HttpRequest,
+    path: web::Path<String>,
+    query: web::Query<PaymentSyncQuery>,
+) -> impl Responder {
+    let connector_transaction_id = path.into_inner();
+    let query = query.into_inner();
+    let payload = PaymentServiceGetRequest {
+        request_ref_id: query
+            .request_ref_id
+            .unwrap_or_else(|| connector_transaction_id.clone()),
+        connector_transaction_id,
+        encoded_data: query.encoded_data,
+    };
+    connector_flow_wrap(
+        PaymentSyncFlow,
+        state,
+        &request,
+        payload,
+        |payment, connector, connector_auth_type, request| async move {
+            payment_sync(&payment, connector, connector_auth_type, request).await
+        },
+    )
+    .await
+}
+
+pub async fn payment_capture_request(
+    state: web::Data<AppState>,
+    request: HttpRequest,
+    payload: web::Json<PaymentServiceCaptureRequest>,
+) -> impl Responder {
+    connector_flow_wrap(
+        CaptureFlow,
+        state,
+        &request,
+        payload.into_inner(),
+        |payment, connector, connector_auth_type, request| async move {
+            payment_capture(&payment, connector, connector_auth_type, request).await
+        },
+    )
+    .await
+}
+
+pub async fn payment_void_request(
+    state: web::Data<AppState>,
+    request: HttpRequest,
+    payload: web::Json<PaymentServiceVoidRequest>,
+) -> impl Responder {
+    connector_flow_wrap(
+        VoidFlow,
+        state,
+        &request,
+        payload.into_inner(),
+        |payment, connector, connector_auth_type, request| async move {
+            payment_void(&payment, connector, connector_auth_type, request).await
+        },
+    )
     .await
 }
 
@@ -62,3 +157,37 @@ impl Authorize {
             )
     }
 }
+
+pub struct Payments;
+
+impl Payments {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/payments")
+            .app_data(web::Data::new(state))
+            .service(web::resource("/{id}/sync").route(web::get().to(payment_sync_request)))
+    }
+}
+
+pub struct Capture;
+
+impl Capture {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/capture")
+            .app_data(web::Data::new(state))
+            .service(
+                web::scope("/v1")
+                    .service(web::resource("").route(web::post().to(payment_capture_request))),
+            )
+    }
+}
+
+pub struct Void;
+
+impl Void {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/void").app_data(web::Data::new(state)).service(
+            web::scope("/v1")
+                .service(web::resource("").route(web::post().to(payment_void_request))),
+        )
+    }
+}
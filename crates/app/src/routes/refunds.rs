@@ -0,0 +1,72 @@
+use crate::services::api::connector_flow_wrap;
+use crate::state::AppState;
+use crate::types::{ApiEventMetric, RefundFlow, RefundSyncFlow};
+use actix_web::{web, HttpRequest, Responder, Scope};
+use grpc::types::{PaymentServiceRefundRequest, RefundServiceGetRequest};
+use payment::core::{payment_refund, refund_sync, RefundResponse};
+
+impl ApiEventMetric for PaymentServiceRefundRequest {
+    fn event_type(&self) -> &'static str {
+        "refund_request"
+    }
+}
+
+impl ApiEventMetric for RefundServiceGetRequest {
+    fn event_type(&self) -> &'static str {
+        "refund_sync_request"
+    }
+}
+
+impl ApiEventMetric for RefundResponse {
+    fn event_type(&self) -> &'static str {
+        "refund_response"
+    }
+}
+
+pub async fn refund_create_request(
+    state: web::Data<AppState>,
+    request: HttpRequest,
+    payload: web::Json<PaymentServiceRefundRequest>,
+) -> impl Responder {
+    connector_flow_wrap(
+        RefundFlow,
+        state,
+        &request,
+        payload.into_inner(),
+        |payment, connector, connector_auth_type, request| async move {
+            payment_refund(&payment, connector, connector_auth_type, request).await
+        },
+    )
+    .await
+}
+
+pub async fn refund_sync_request(
+    state: web::Data<AppState>,
+    request: HttpRequest,
+    payload: web::Json<RefundServiceGetRequest>,
+) -> impl Responder {
+    connector_flow_wrap(
+        RefundSyncFlow,
+        state,
+        &request,
+        payload.into_inner(),
+        |payment, connector, connector_auth_type, request| async move {
+            refund_sync(&payment, connector, connector_auth_type, request).await
+        },
+    )
+    .await
+}
+
+pub struct Refunds;
+
+impl Refunds {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/refunds")
+            .app_data(web::Data::new(state))
+            .service(
+                web::scope("/v1")
+                    .service(web::resource("").route(web::post().to(refund_create_request)))
+                    .service(web::resource("/sync").route(web::post().to(refund_sync_request))),
+            )
+    }
+}
@@ -5,25 +5,26 @@ use std::time::Instant;
 use serde::Serialize;
 use std::fmt::Debug;
 use error_stack::Result as ErrorStackResult;
-use crate::types::{ApplicationResponse, SessionState, ReqState, Tag, FlowMetric, ApiEventMetric};
+use tracing::Instrument;
+use crate::types::{ApplicationResponse, SessionState, ReqState, Tag, FlowMetric, ApiEventMetric, ErrorResponse};
 use crate::state::AppState;
 use crate::http_utils::*;
+use crate::services::authentication::ConnectorAuthHeaders;
+use domain_types::{
+    connector_types::ConnectorEnum, errors::ApplicationErrorResponse,
+    router_data_v2::ConnectorAuthType,
+};
+use payment::payment::Payment;
 
 pub type CustomResult<T, E> = ErrorStackResult<T, E>;
 
-// Logger module
-pub mod logger {
-    pub fn info<T: std::fmt::Debug>(msg: T) {
-        tracing::info!("{:?}", msg);
-    }
-}
-
 // Updated server_wrap_util to auto-wrap responses
 pub async fn server_wrap_util<T, Q, F, Fut, E>(
     flow: &impl FlowMetric,
     state: web::Data<AppState>,
     _incoming_request_header: &HeaderMap,
     request: &HttpRequest,
+    request_id: String,
     payload: T,
     func: F,
 ) -> CustomResult<ApplicationResponse<Q>, E>
@@ -42,15 +43,11 @@ where
     
     // Create request state
     let req_state = ReqState {
-        request_id: uuid::Uuid::new_v4().to_string(),
+        request_id,
         flow_name: flow.flow_name().to_string(),
     };
     
-    tracing::info!(
-        "Processing request: {} for flow: {}", 
-        req_state.request_id, 
-        req_state.flow_name
-    );
+    tracing::info!("processing request");
     
     // Execute business logic and auto-wrap in ApplicationResponse::Json
     match func(session_state.clone(), session_state, payload, req_state).await {
@@ -72,7 +69,7 @@ where
     Fut: Future<Output = CustomResult<Q, E>>,  // Note: Returns Q directly, not ApplicationResponse<Q>
     Q: Serialize + Debug + ApiEventMetric + 'a,
     T: Debug + Serialize + ApiEventMetric,
-    E: std::error::Error + Send + Sync + 'static,
+    E: std::error::Error + ErrorResponse + Send + Sync + 'static,
 {
     let request_method = request.method().as_str();
     let url_path = request.path();
@@ -94,79 +91,136 @@ where
                 acc
             });
 
-    tracing::Span::current().record("request_method", request_method);
-    tracing::Span::current().record("request_url_path", url_path);
+    let request_id = uuid::Uuid::new_v4().to_string();
+    // connector and merchant are recorded by the route once the auth headers are read
+    let span = tracing::info_span!(
+        "request",
+        request_id = %request_id,
+        flow = flow.flow_name(),
+        connector = tracing::field::Empty,
+        merchant_id = tracing::field::Empty,
+        request_method,
+        request_url_path = url_path,
+    );
 
-    let _start_instant = Instant::now();
+    async move {
+        let start_instant = Instant::now();
 
-    logger::info(format!(
-        "tag = {:?}, payload = {:?}, headers = {:?}",
-        Tag::BeginRequest, payload, incoming_header_to_log
-    ));
+        tracing::info!(
+            tag = ?Tag::BeginRequest,
+            payload = ?payload,
+            headers = ?incoming_header_to_log,
+            "request received"
+        );
 
-    let server_wrap_util_res = server_wrap_util(
-        &flow,
-        state.clone(),
-        incoming_request_header,
-        request,
-        payload,
-        func,
-    )
-    .await
-    .map(|response| {
-        logger::info(format!("api_response = {:?}", response));
-        response
-    });
+        let server_wrap_util_res = server_wrap_util(
+            &flow,
+            state.clone(),
+            incoming_request_header,
+            request,
+            request_id,
+            payload,
+            func,
+        )
+        .await
+        .map(|response| {
+            tracing::info!(api_response = ?response, "request processed");
+            response
+        });
 
-    let res = match server_wrap_util_res {
-        Ok(ApplicationResponse::Json(response)) => match serde_json::to_string(&response) {
-            Ok(res) => http_response_json(res),
-            Err(_) => http_response_err(
-                r#"{
-                    "error": {
-                        "message": "Error serializing response from connector"
-                    }
-                }"#,
-            ),
-        },
-        Ok(ApplicationResponse::StatusOk) => http_response_ok(),
-        Ok(ApplicationResponse::TextPlain(text)) => http_response_plaintext(text),
-        Ok(ApplicationResponse::FileData((file_data, content_type))) => {
-            http_response_file_data(file_data, content_type)
-        }
-        Ok(ApplicationResponse::JsonForRedirection(response)) => {
-            match serde_json::to_string(&response) {
-                Ok(res) => http_redirect_response(res, response),
+        let res = match server_wrap_util_res {
+            Ok(ApplicationResponse::Json(response)) => match serde_json::to_string(&response) {
+                Ok(res) => http_response_json(res),
                 Err(_) => http_response_err(
                     r#"{
-                    "error": {
-                        "message": "Error serializing response from connector"
-                    }
-                }"#,
+                        "error": {
+                            "message": "Error serializing response from connector"
+                        }
+                    }"#,
                 ),
+            },
+            Ok(ApplicationResponse::StatusOk) => http_response_ok(),
+            Ok(ApplicationResponse::TextPlain(text)) => http_response_plaintext(text),
+            Ok(ApplicationResponse::FileData((file_data, content_type))) => {
+                http_response_file_data(file_data, content_type)
             }
-        }
-        Ok(ApplicationResponse::Form(redirection_data)) => {
-            let config = state.conf();
-            build_redirection_form(
-                &redirection_data.redirect_form,
-                redirection_data.payment_method_data,
-                redirection_data.amount,
-                redirection_data.currency,
-                config,
-            )
-        }
-        Err(err) => {
-            tracing::error!("Request failed: {:?}", err);
-            http_response_err(
-                r#"{
-                    "error": {
-                        "message": "Internal server error"
-                    }
-                }"#,
-            )
-        }
-    };
+            Ok(ApplicationResponse::JsonForRedirection(response)) => {
+                match serde_json::to_string(&response) {
+                    Ok(res) => http_redirect_response(res, response),
+                    Err(_) => http_response_err(
+                        r#"{
+                        "error": {
+                            "message": "Error serializing response from connector"
+                        }
+                    }"#,
+                    ),
+                }
+            }
+            Ok(ApplicationResponse::Form(redirection_data)) => {
+                let config = state.conf();
+                build_redirection_form(
+                    &redirection_data.redirect_form,
+                    redirection_data.payment_method_data,
+                    redirection_data.amount,
+                    redirection_data.currency,
+                    config,
+                )
+            }
+            Err(err) => {
+                tracing::error!(error = ?err, "request failed");
+                err.current_context().error_response()
+            }
+        };
+
+        tracing::info!(
+            status_code = res.status().as_u16(),
+            latency_ms = u64::try_from(start_instant.elapsed().as_millis()).unwrap_or(u64::MAX),
+            "request completed"
+        );
+        res
+    }
+    .instrument(span)
+    .await
+}
 
-    res
+/// `server_wrap` for the connector flows, which run with the connector and credentials sent in
+/// the request headers. The headers are read inside `server_wrap` so that rejected ones are
+/// logged under the request span like any other failure
+pub async fn connector_flow_wrap<'a, T, Q, F, Fut>(
+    flow: impl FlowMetric,
+    state: web::Data<AppState>,
+    request: &'a HttpRequest,
+    payload: T,
+    operation: F,
+) -> HttpResponse
+where
+    F: Fn(Payment, ConnectorEnum, ConnectorAuthType, T) -> Fut,
+    Fut: Future<Output = CustomResult<Q, ApplicationErrorResponse>>,
+    Q: Serialize + Debug + ApiEventMetric + 'a,
+    T: Debug + Serialize + ApiEventMetric,
+{
+    let app_state = state.get_ref().clone();
+    Box::pin(server_wrap(
+        flow,
+        state,
+        request,
+        payload,
+        move |_session_state: SessionState,
+              _user_data: SessionState,
+              payload: T,
+              _req_state: ReqState| {
+            let operation_result =
+                ConnectorAuthHeaders::try_from(request.headers()).map(|auth_headers| {
+                    auth_headers.record_in_span();
+                    operation(
+                        app_state.payment(),
+                        auth_headers.connector,
+                        auth_headers.connector_auth_type,
+                        payload,
+                    )
+                });
+            async move { operation_result?.await }
+        },
+    ))
+    .await
 }
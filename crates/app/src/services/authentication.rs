@@ -0,0 +1,140 @@
+use actix_web::http::header::HeaderMap;
+use domain_types::{
+    connector_types::ConnectorEnum,
+    errors::{ApiError, ApplicationErrorResponse},
+    router_data_v2::ConnectorAuthType,
+};
+use error_stack::{report, Report};
+use std::str::FromStr;
+
+pub const X_CONNECTOR: &str = "x-connector";
+pub const X_AUTH: &str = "x-auth";
+pub const X_API_KEY: &str = "x-api-key";
+pub const X_KEY1: &str = "x-key1";
+pub const X_API_SECRET: &str = "x-api-secret";
+pub const X_MERCHANT_ID: &str = "x-merchant-id";
+
+/// Connector and credentials a request has to be processed with, as sent in the request headers
+#[derive(Debug, Clone)]
+pub struct ConnectorAuthHeaders {
+    pub connector: ConnectorEnum,
+    pub connector_auth_type: ConnectorAuthType,
+    pub merchant_id: String,
+}
+
+impl ConnectorAuthHeaders {
+    /// Tags the request span, and every connector call logged under it, with who is calling
+    pub fn record_in_span(&self) {
+        tracing::Span::current()
+            .record("connector", tracing::field::display(self.connector))
+            .record("merchant_id", self.merchant_id.as_str());
+    }
+}
+
+impl TryFrom<&HeaderMap> for ConnectorAuthHeaders {
+    type Error = Report<ApplicationErrorResponse>;
+
+    fn try_from(headers: &HeaderMap) -> Result<Self, Self::Error> {
+        Ok(Self {
+            connector: connector_from_headers(headers)?,
+            connector_auth_type: auth_from_headers(headers)?,
+            merchant_id: parse_header(headers, X_MERCHANT_ID)?,
+        })
+    }
+}
+
+pub fn connector_from_headers(
+    headers: &HeaderMap,
+) -> Result<ConnectorEnum, Report<ApplicationErrorResponse>> {
+    let connector = parse_header(headers, X_CONNECTOR)?;
+    ConnectorEnum::from_str(&connector).map_err(|_| {
+        report!(bad_request(
+            "INVALID_CONNECTOR",
+            format!("Connector '{connector}' is not supported"),
+        ))
+    })
+}
+
+pub fn auth_from_headers(
+    headers: &HeaderMap,
+) -> Result<ConnectorAuthType, Report<ApplicationErrorResponse>> {
+    let auth = parse_header(headers, X_AUTH)?;
+    let connector_auth_type = match auth.as_str() {
+        "header-key" => {
+            reject_headers(headers, &auth, &[X_KEY1, X_API_SECRET])?;
+            ConnectorAuthType::HeaderKey {
+                api_key: parse_header(headers, X_API_KEY)?.into(),
+            }
+        }
+        "body-key" => {
+            reject_headers(headers, &auth, &[X_API_SECRET])?;
+            ConnectorAuthType::BodyKey {
+                api_key: parse_header(headers, X_API_KEY)?.into(),
+                key1: parse_header(headers, X_KEY1)?.into(),
+            }
+        }
+        "signature-key" => ConnectorAuthType::SignatureKey {
+            api_key: parse_header(headers, X_API_KEY)?.into(),
+            key1: parse_header(headers, X_KEY1)?.into(),
+            api_secret: parse_header(headers, X_API_SECRET)?.into(),
+        },
+        _ => Err(report!(bad_request(
+            "INVALID_AUTH_TYPE",
+            format!("Auth type '{auth}' is not supported"),
+        )))?,
+    };
+    Ok(connector_auth_type)
+}
+
+fn parse_header(
+    headers: &HeaderMap,
+    key: &str,
+) -> Result<String, Report<ApplicationErrorResponse>> {
+    let value = headers
+        .get(key)
+        .ok_or_else(|| {
+            report!(bad_request(
+                "MISSING_HEADER",
+                format!("Missing required header: {key}"),
+            ))
+        })?
+        .to_str()
+        .map_err(|_| {
+            report!(bad_request(
+                "INVALID_HEADER",
+                format!("Header {key} is not a valid string"),
+            ))
+        })?
+        .trim();
+    if value.is_empty() {
+        return Err(report!(bad_request(
+            "MISSING_HEADER",
+            format!("Header {key} must not be empty"),
+        )));
+    }
+    Ok(value.to_string())
+}
+
+/// Credentials which are not used by the requested auth type are rejected rather than dropped
+fn reject_headers(
+    headers: &HeaderMap,
+    auth: &str,
+    keys: &[&str],
+) -> Result<(), Report<ApplicationErrorResponse>> {
+    match keys.iter().find(|key| headers.contains_key(**key)) {
+        Some(key) => Err(report!(bad_request(
+            "INCONSISTENT_AUTH_HEADERS",
+            format!("Header {key} is not used with auth type '{auth}'"),
+        ))),
+        None => Ok(()),
+    }
+}
+
+fn bad_request(sub_code: &str, error_message: String) -> ApplicationErrorResponse {
+    ApplicationErrorResponse::BadRequest(ApiError {
+        sub_code: sub_code.to_string(),
+        error_identifier: 400,
+        error_message,
+        error_object: None,
+    })
+}
@@ -1,4 +1,7 @@
+use crate::configs::{Server, Settings};
 use crate::types::HeaderMaskingConfig;
+use domain_types::types::{Connectors, Proxy};
+use payment::payment::Payment;
 use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
 use uuid::Uuid;
@@ -6,12 +9,18 @@ use uuid::Uuid;
 #[derive(Clone)]
 pub struct AppConfig {
     pub unmasked_headers: HeaderMaskingConfig,
+    pub server: Server,
+    pub proxy: Proxy,
+    pub connectors: Connectors,
 }
 
-impl Default for AppConfig {
-    fn default() -> Self {
+impl From<Settings> for AppConfig {
+    fn from(settings: Settings) -> Self {
         Self {
             unmasked_headers: HeaderMaskingConfig::default(),
+            server: settings.server,
+            proxy: settings.proxy,
+            connectors: settings.connectors,
         }
     }
 }
@@ -25,15 +34,19 @@ pub struct AppState {
 }
 
 impl AppState {
-    pub fn new() -> Self {
+    pub fn new(config: AppConfig) -> Self {
         Self {
             flow_name: "vehicle_management".to_string(),
             // vehicles: Arc::new(Mutex::new(HashMap::new())),
-            config: AppConfig::default(),
+            config,
         }
     }
 
     pub fn conf(&self) -> &AppConfig {
         &self.config
     }
+
+    pub fn payment(&self) -> Payment {
+        Payment::new(self.config.connectors.clone(), self.config.proxy.clone())
+    }
 }
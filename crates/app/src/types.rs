@@ -1,4 +1,6 @@
-use actix_web::http::header::HeaderValue;
+use actix_web::http::{header::HeaderValue, StatusCode};
+use actix_web::HttpResponse;
+use domain_types::errors::{ApiError, ApplicationErrorResponse};
 use serde::{Deserialize, Serialize};
 use std::fmt::Debug;
 
@@ -46,6 +48,39 @@ pub trait FlowMetric {
     fn flow_name(&self) -> &str;
 }
 
+// Error Response trait, mirrors actix's ResponseError for errors defined outside this crate
+pub trait ErrorResponse {
+    fn status_code(&self) -> StatusCode;
+    fn api_error(&self) -> &ApiError;
+
+    fn error_response(&self) -> HttpResponse {
+        HttpResponse::build(self.status_code()).json(self.api_error())
+    }
+}
+
+impl ErrorResponse for ApplicationErrorResponse {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            Self::Unauthorized(_) => StatusCode::UNAUTHORIZED,
+            Self::ForbiddenCommonResource(_) | Self::ForbiddenPrivateResource(_) => {
+                StatusCode::FORBIDDEN
+            }
+            Self::Conflict(_) => StatusCode::CONFLICT,
+            Self::Gone(_) => StatusCode::GONE,
+            Self::Unprocessable(_) => StatusCode::UNPROCESSABLE_ENTITY,
+            Self::InternalServerError(_) => StatusCode::INTERNAL_SERVER_ERROR,
+            Self::NotImplemented(_) => StatusCode::NOT_IMPLEMENTED,
+            Self::NotFound(_) => StatusCode::NOT_FOUND,
+            Self::MethodNotAllowed(_) => StatusCode::METHOD_NOT_ALLOWED,
+            Self::BadRequest(_) | Self::DomainError(_) => StatusCode::BAD_REQUEST,
+        }
+    }
+
+    fn api_error(&self) -> &ApiError {
+        self.get_api_error()
+    }
+}
+
 // Vehicle Flow
 pub struct VehicleFlow;
 
@@ -63,6 +98,46 @@ impl FlowMetric for AuthorizeFlow {
     }
 }
 
+pub struct PaymentSyncFlow;
+
+impl FlowMetric for PaymentSyncFlow {
+    fn flow_name(&self) -> &str {
+        "psync"
+    }
+}
+
+pub struct CaptureFlow;
+
+impl FlowMetric for CaptureFlow {
+    fn flow_name(&self) -> &str {
+        "capture"
+    }
+}
+
+pub struct VoidFlow;
+
+impl FlowMetric for VoidFlow {
+    fn flow_name(&self) -> &str {
+        "void"
+    }
+}
+
+pub struct RefundFlow;
+
+impl FlowMetric for RefundFlow {
+    fn flow_name(&self) -> &str {
+        "refund"
+    }
+}
+
+pub struct RefundSyncFlow;
+
+impl FlowMetric for RefundSyncFlow {
+    fn flow_name(&self) -> &str {
+        "refund_sync"
+    }
+}
+
 // Configuration for header masking
 #[derive(Debug, Clone)]
 pub struct HeaderMaskingConfig {
@@ -72,7 +147,12 @@ pub struct HeaderMaskingConfig {
 impl Default for HeaderMaskingConfig {
     fn default() -> Self {
         Self {
-            keys: vec!["authorization".to_string(), "x-api-key".to_string()],
+            // Only headers listed here are logged as is, credential headers stay masked
+            keys: vec![
+                "x-connector".to_string(),
+                "x-auth".to_string(),
+                "x-merchant-id".to_string(),
+            ],
         }
     }
 }
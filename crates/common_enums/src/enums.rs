@@ -1,5 +1,6 @@
 #[derive(
     Clone,
+    Copy,
     Debug,
     Eq,
     Hash,
@@ -11,9 +12,365 @@
     strum::EnumIter,
     strum::EnumString,
 )]
+#[strum(ascii_case_insensitive)]
+#[allow(clippy::upper_case_acronyms)]
 pub enum CardNetwork {
     #[serde(alias = "VISA")]
     Visa,
+    #[serde(alias = "MASTERCARD")]
+    Mastercard,
+    #[serde(alias = "AMERICANEXPRESS", alias = "AMEX")]
+    #[strum(to_string = "AmericanExpress", serialize = "AMEX")]
+    AmericanExpress,
+    JCB,
+    #[serde(alias = "DINERSCLUB")]
+    DinersClub,
+    #[serde(alias = "DISCOVER")]
+    Discover,
+    #[serde(alias = "CARTESBANCAIRES")]
+    CartesBancaires,
+    #[serde(alias = "UNIONPAY")]
+    UnionPay,
+    #[serde(alias = "MAESTRO")]
+    Maestro,
+    #[serde(alias = "RUPAY")]
+    RuPay,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum AttemptStatus {
+    Started,
+    AuthenticationFailed,
+    RouterDeclined,
+    AuthenticationPending,
+    AuthenticationSuccessful,
+    Authorized,
+    AuthorizationFailed,
+    Charged,
+    Authorizing,
+    CodInitiated,
+    Voided,
+    VoidInitiated,
+    CaptureInitiated,
+    CaptureFailed,
+    VoidFailed,
+    AutoRefunded,
+    PartialCharged,
+    PartialChargedAndChargeable,
+    Unresolved,
+    #[default]
+    Pending,
+    Failure,
+    PaymentMethodAwaited,
+    ConfirmationAwaited,
+    DeviceDataCollectionPending,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum RefundStatus {
+    Failure,
+    ManualReview,
+    #[default]
+    Pending,
+    Success,
+    TransactionFailure,
+}
+
+/// ISO 4217 currency codes
+#[allow(clippy::upper_case_acronyms)]
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+)]
+pub enum Currency {
+    AED,
+    AFN,
+    ALL,
+    AMD,
+    ANG,
+    AOA,
+    ARS,
+    AUD,
+    AWG,
+    AZN,
+    BAM,
+    BBD,
+    BDT,
+    BGN,
+    BHD,
+    BIF,
+    BMD,
+    BND,
+    BOB,
+    BRL,
+    BSD,
+    BTN,
+    BWP,
+    BYN,
+    BZD,
+    CAD,
+    CDF,
+    CHF,
+    CLP,
+    CNY,
+    COP,
+    CRC,
+    CUP,
+    CVE,
+    CZK,
+    DJF,
+    DKK,
+    DOP,
+    DZD,
+    EGP,
+    ERN,
+    ETB,
+    EUR,
+    FJD,
+    FKP,
+    GBP,
+    GEL,
+    GHS,
+    GIP,
+    GMD,
+    GNF,
+    GTQ,
+    GYD,
+    HKD,
+    HNL,
+    HTG,
+    HUF,
+    IDR,
+    ILS,
+    INR,
+    IQD,
+    IRR,
+    ISK,
+    JMD,
+    JOD,
+    JPY,
+    KES,
+    KGS,
+    KHR,
+    KMF,
+    KPW,
+    KRW,
+    KWD,
+    KYD,
+    KZT,
+    LAK,
+    LBP,
+    LKR,
+    LRD,
+    LSL,
+    LYD,
+    MAD,
+    MDL,
+    MGA,
+    MKD,
+    MMK,
+    MNT,
+    MOP,
+    MRU,
+    MUR,
+    MVR,
+    MWK,
+    MXN,
+    MYR,
+    MZN,
+    NAD,
+    NGN,
+    NIO,
+    NOK,
+    NPR,
+    NZD,
+    OMR,
+    PAB,
+    PEN,
+    PGK,
+    PHP,
+    PKR,
+    PLN,
+    PYG,
+    QAR,
+    RON,
+    RSD,
+    RUB,
+    RWF,
+    SAR,
+    SBD,
+    SCR,
+    SDG,
+    SEK,
+    SGD,
+    SHP,
+    SLE,
+    SOS,
+    SRD,
+    SSP,
+    STN,
+    SVC,
+    SYP,
+    SZL,
+    THB,
+    TJS,
+    TMT,
+    TND,
+    TOP,
+    TRY,
+    TTD,
+    TWD,
+    TZS,
+    UAH,
+    UGX,
+    #[default]
+    USD,
+    UYU,
+    UZS,
+    VES,
+    VND,
+    VUV,
+    WST,
+    XAF,
+    XCD,
+    XOF,
+    XPF,
+    YER,
+    ZAR,
+    ZMW,
+    ZWL,
+}
+
+impl Currency {
+    /// Number of digits after the decimal point in the currency's minor unit
+    pub fn number_of_digits_after_decimal_point(self) -> u8 {
+        if self.is_zero_decimal_currency() {
+            0
+        } else if self.is_three_decimal_currency() {
+            3
+        } else {
+            2
+        }
+    }
+
+    pub fn is_zero_decimal_currency(self) -> bool {
+        matches!(
+            self,
+            Self::BIF
+                | Self::CLP
+                | Self::DJF
+                | Self::GNF
+                | Self::ISK
+                | Self::JPY
+                | Self::KMF
+                | Self::KRW
+                | Self::MGA
+                | Self::PYG
+                | Self::RWF
+                | Self::UGX
+                | Self::VND
+                | Self::VUV
+                | Self::XAF
+                | Self::XOF
+                | Self::XPF
+        )
+    }
+
+    pub fn is_three_decimal_currency(self) -> bool {
+        matches!(
+            self,
+            Self::BHD | Self::IQD | Self::JOD | Self::KWD | Self::LYD | Self::OMR | Self::TND
+        )
+    }
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum CaptureMethod {
+    /// Capture is triggered by the connector right after authorization
+    #[default]
+    Automatic,
+    /// Capture has to be requested separately once the payment is authorized
+    Manual,
+    /// Multiple partial captures are requested separately
+    ManualMultiple,
+    /// Capture is triggered by the connector at a scheduled time
+    Scheduled,
+    /// Authorization and capture are sent as two calls, one right after the other
+    SequentialAutomatic,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum FutureUsage {
+    /// The payment method is stored to be charged later without the customer being present
+    #[default]
+    OffSession,
+    /// The payment method is stored to be charged later while the customer is present
+    OnSession,
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -43,6 +400,8 @@ pub enum ApiClientError {
     UrlEncodingFailed,
     #[error("Failed to send request to connector {0}")]
     RequestNotSent(String),
+    #[error("Failed to establish a connection with the connector")]
+    ConnectionFailed,
     #[error("Failed to decode response")]
     ResponseDecodingFailed,
 
@@ -1 +1,34 @@
+use common_enums::Currency;
+
 pub type CustomResult<T, E> = error_stack::Result<T, E>;
+
+/// Parsing Errors
+#[derive(Debug, thiserror::Error)]
+pub enum ParsingError {
+    /// Failed to parse the bytes into the given struct
+    #[error("Failed to parse struct: {0}")]
+    StructParseFailure(&'static str),
+}
+
+/// Amount conversion errors
+#[derive(Debug, thiserror::Error)]
+pub enum AmountConversionError {
+    /// The amount does not fit the unit it is converted to
+    #[error("Failed to convert amount of type {0}")]
+    AmountConversionFailed(&'static str),
+    /// A float amount could not be represented as a decimal
+    #[error("Failed to convert float to decimal")]
+    FloatToDecimalConversionFailure,
+    /// A string amount could not be parsed as a decimal
+    #[error("Failed to convert string to decimal")]
+    StringToDecimalConversionFailure,
+    /// A decimal amount could not be represented as an i64
+    #[error("Failed to convert decimal to i64")]
+    DecimalToI64ConversionFailure,
+    /// A decimal amount could not be represented as an f64
+    #[error("Failed to convert decimal to f64")]
+    DecimalToF64ConversionFailure,
+    /// A major unit amount has more decimal places than its currency allows
+    #[error("Amount has more decimal places than {0} allows")]
+    AmountPrecisionExceeded(Currency),
+}
@@ -1,3 +1,25 @@
+use crate::errors::{CustomResult, ParsingError};
+use error_stack::ResultExt;
+
+/// Extending functionalities of `bytes::Bytes`
+pub trait BytesExt {
+    /// Convert `bytes::Bytes` into type `<T>` using `serde::Deserialize`
+    fn parse_struct<'de, T>(&'de self, type_name: &'static str) -> CustomResult<T, ParsingError>
+    where
+        T: serde::Deserialize<'de>;
+}
+
+impl BytesExt for bytes::Bytes {
+    fn parse_struct<'de, T>(&'de self, type_name: &'static str) -> CustomResult<T, ParsingError>
+    where
+        T: serde::Deserialize<'de>,
+    {
+        serde_json::from_slice::<T>(self)
+            .change_context(ParsingError::StructParseFailure(type_name))
+            .attach_printable_lazy(|| format!("Unable to parse {type_name} from bytes"))
+    }
+}
+
 // #[cfg(feature = "async_ext")]
 #[cfg_attr(feature = "async_ext", async_trait::async_trait)]
 impl<A: Send, E: Send + std::fmt::Debug> AsyncExt<A> for Result<A, E> {
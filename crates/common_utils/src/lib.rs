@@ -1,3 +1,4 @@
 pub mod errors;
 pub mod ext_traits;
+pub mod money;
 pub mod request;
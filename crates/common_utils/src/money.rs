@@ -0,0 +1,403 @@
+//! Amounts and the units connectors expect them in
+
+use crate::errors::{AmountConversionError, CustomResult};
+use common_enums::Currency;
+use error_stack::{report, ResultExt};
+use rust_decimal::{
+    prelude::{FromPrimitive, ToPrimitive},
+    Decimal,
+};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// Amount in the smallest unit of its currency, e.g. cents for USD
+#[derive(
+    Default, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+#[serde(transparent)]
+pub struct MinorUnit(i64);
+
+impl MinorUnit {
+    pub fn new(value: i64) -> Self {
+        Self(value)
+    }
+
+    pub fn zero() -> Self {
+        Self(0)
+    }
+
+    pub fn get_amount_as_i64(self) -> i64 {
+        self.0
+    }
+
+    /// Adds two amounts, returning `None` on overflow
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        self.0.checked_add(other.0).map(Self)
+    }
+
+    /// Subtracts two amounts, returning `None` on overflow
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        self.0.checked_sub(other.0).map(Self)
+    }
+
+    /// Converts to a major unit string, e.g. `1099` USD becomes `"10.99"`
+    pub fn to_major_unit_as_string(
+        self,
+        currency: Currency,
+    ) -> CustomResult<StringMajorUnit, AmountConversionError> {
+        let amount_decimal = self.to_major_unit_as_decimal(currency)?;
+        let amount_string = if currency.is_zero_decimal_currency() {
+            amount_decimal.trunc().to_string()
+        } else {
+            format!(
+                "{:.*}",
+                usize::from(currency.number_of_digits_after_decimal_point()),
+                amount_decimal
+            )
+        };
+        Ok(StringMajorUnit::new(amount_string))
+    }
+
+    /// Converts to a major unit float, e.g. `1099` USD becomes `10.99`
+    pub fn to_major_unit_as_f64(
+        self,
+        currency: Currency,
+    ) -> CustomResult<FloatMajorUnit, AmountConversionError> {
+        let amount_decimal = self.to_major_unit_as_decimal(currency)?;
+        let amount_f64 = amount_decimal.to_f64().ok_or(report!(
+            AmountConversionError::DecimalToF64ConversionFailure
+        ))?;
+        Ok(FloatMajorUnit::new(amount_f64))
+    }
+
+    /// Converts to a minor unit string, e.g. `1099` becomes `"1099"`
+    pub fn to_minor_unit_as_string(self) -> StringMinorUnit {
+        StringMinorUnit::new(self.0.to_string())
+    }
+
+    fn to_major_unit_as_decimal(
+        self,
+        currency: Currency,
+    ) -> CustomResult<Decimal, AmountConversionError> {
+        let mut amount_decimal = Decimal::from(self.0);
+        amount_decimal
+            .set_scale(u32::from(currency.number_of_digits_after_decimal_point()))
+            .change_context(AmountConversionError::AmountConversionFailed("MinorUnit"))?;
+        Ok(amount_decimal)
+    }
+}
+
+impl Display for MinorUnit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Amount in the major unit of its currency as a string, e.g. `"10.99"` for USD
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct StringMajorUnit(String);
+
+impl StringMajorUnit {
+    fn new(value: String) -> Self {
+        Self(value)
+    }
+
+    pub fn get_amount_as_string(&self) -> String {
+        self.0.clone()
+    }
+
+    pub fn to_minor_unit_as_i64(
+        &self,
+        currency: Currency,
+    ) -> CustomResult<MinorUnit, AmountConversionError> {
+        let amount_decimal = self
+            .0
+            .parse::<Decimal>()
+            .change_context(AmountConversionError::StringToDecimalConversionFailure)?;
+        decimal_to_minor_unit(amount_decimal, currency)
+    }
+}
+
+/// Amount in the major unit of its currency as a float, e.g. `10.99` for USD
+#[derive(Default, Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct FloatMajorUnit(f64);
+
+impl FloatMajorUnit {
+    fn new(value: f64) -> Self {
+        Self(value)
+    }
+
+    pub fn get_amount_as_f64(self) -> f64 {
+        self.0
+    }
+
+    pub fn to_minor_unit_as_i64(
+        self,
+        currency: Currency,
+    ) -> CustomResult<MinorUnit, AmountConversionError> {
+        let amount_decimal = Decimal::from_f64(self.0).ok_or(report!(
+            AmountConversionError::FloatToDecimalConversionFailure
+        ))?;
+        decimal_to_minor_unit(amount_decimal, currency)
+    }
+}
+
+/// Amount in the minor unit of its currency as a string, e.g. `"1099"` for USD
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct StringMinorUnit(String);
+
+impl StringMinorUnit {
+    fn new(value: String) -> Self {
+        Self(value)
+    }
+
+    pub fn to_minor_unit_as_i64(&self) -> CustomResult<MinorUnit, AmountConversionError> {
+        let amount =
+            self.0
+                .parse::<i64>()
+                .change_context(AmountConversionError::AmountConversionFailed(
+                    "StringMinorUnit",
+                ))?;
+        Ok(MinorUnit::new(amount))
+    }
+}
+
+/// Scales a major unit amount to minor units, rejecting amounts that overflow
+/// or carry more decimal places than the currency has
+fn decimal_to_minor_unit(
+    amount_decimal: Decimal,
+    currency: Currency,
+) -> CustomResult<MinorUnit, AmountConversionError> {
+    let multiplier =
+        Decimal::from(10_i64.pow(u32::from(currency.number_of_digits_after_decimal_point())));
+    let minor_amount = amount_decimal.checked_mul(multiplier).ok_or(report!(
+        AmountConversionError::DecimalToI64ConversionFailure
+    ))?;
+    if !minor_amount.fract().is_zero() {
+        return Err(report!(AmountConversionError::AmountPrecisionExceeded(
+            currency
+        )));
+    }
+    let amount = minor_amount.to_i64().ok_or(report!(
+        AmountConversionError::DecimalToI64ConversionFailure
+    ))?;
+    Ok(MinorUnit::new(amount))
+}
+
+/// Renders amounts in the unit a connector expects, and reads them back
+pub trait AmountConvertor: Send {
+    /// Unit the connector expects amounts in
+    type Output;
+
+    fn convert(
+        &self,
+        amount: MinorUnit,
+        currency: Currency,
+    ) -> CustomResult<Self::Output, AmountConversionError>;
+
+    fn convert_back(
+        &self,
+        amount: Self::Output,
+        currency: Currency,
+    ) -> CustomResult<MinorUnit, AmountConversionError>;
+}
+
+/// For connectors taking the amount in minor units as a number
+#[derive(Default, Debug, Clone, Copy, PartialEq)]
+pub struct MinorUnitForConnector;
+
+impl AmountConvertor for MinorUnitForConnector {
+    type Output = MinorUnit;
+
+    fn convert(
+        &self,
+        amount: MinorUnit,
+        _currency: Currency,
+    ) -> CustomResult<Self::Output, AmountConversionError> {
+        Ok(amount)
+    }
+
+    fn convert_back(
+        &self,
+        amount: MinorUnit,
+        _currency: Currency,
+    ) -> CustomResult<MinorUnit, AmountConversionError> {
+        Ok(amount)
+    }
+}
+
+/// For connectors taking the amount in minor units as a string
+#[derive(Default, Debug, Clone, Copy, PartialEq)]
+pub struct StringMinorUnitForConnector;
+
+impl AmountConvertor for StringMinorUnitForConnector {
+    type Output = StringMinorUnit;
+
+    fn convert(
+        &self,
+        amount: MinorUnit,
+        _currency: Currency,
+    ) -> CustomResult<Self::Output, AmountConversionError> {
+        Ok(amount.to_minor_unit_as_string())
+    }
+
+    fn convert_back(
+        &self,
+        amount: StringMinorUnit,
+        _currency: Currency,
+    ) -> CustomResult<MinorUnit, AmountConversionError> {
+        amount.to_minor_unit_as_i64()
+    }
+}
+
+/// For connectors taking the amount in major units as a string
+#[derive(Default, Debug, Clone, Copy, PartialEq)]
+pub struct StringMajorUnitForConnector;
+
+impl AmountConvertor for StringMajorUnitForConnector {
+    type Output = StringMajorUnit;
+
+    fn convert(
+        &self,
+        amount: MinorUnit,
+        currency: Currency,
+    ) -> CustomResult<Self::Output, AmountConversionError> {
+        amount.to_major_unit_as_string(currency)
+    }
+
+    fn convert_back(
+        &self,
+        amount: StringMajorUnit,
+        currency: Currency,
+    ) -> CustomResult<MinorUnit, AmountConversionError> {
+        amount.to_minor_unit_as_i64(currency)
+    }
+}
+
+/// For connectors taking the amount in major units as a number
+#[derive(Default, Debug, Clone, Copy, PartialEq)]
+pub struct FloatMajorUnitForConnector;
+
+impl AmountConvertor for FloatMajorUnitForConnector {
+    type Output = FloatMajorUnit;
+
+    fn convert(
+        &self,
+        amount: MinorUnit,
+        currency: Currency,
+    ) -> CustomResult<Self::Output, AmountConversionError> {
+        amount.to_major_unit_as_f64(currency)
+    }
+
+    fn convert_back(
+        &self,
+        amount: FloatMajorUnit,
+        currency: Currency,
+    ) -> CustomResult<MinorUnit, AmountConversionError> {
+        amount.to_minor_unit_as_i64(currency)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn round_trip<T: AmountConvertor>(convertor: &T, amount: i64, currency: Currency) -> MinorUnit {
+        let converted = convertor
+            .convert(MinorUnit::new(amount), currency)
+            .expect("amount should convert");
+        convertor
+            .convert_back(converted, currency)
+            .expect("amount should convert back")
+    }
+
+    #[test]
+    fn string_major_unit_follows_currency_exponent() {
+        let convertor = StringMajorUnitForConnector;
+        let cases = [
+            (1099, Currency::USD, "10.99"),
+            (1099, Currency::JPY, "1099"),
+            (1099, Currency::KWD, "1.099"),
+            (0, Currency::USD, "0.00"),
+        ];
+        for (amount, currency, expected) in cases {
+            let converted = convertor
+                .convert(MinorUnit::new(amount), currency)
+                .expect("amount should convert");
+            assert_eq!(converted.get_amount_as_string(), expected);
+            assert_eq!(
+                round_trip(&convertor, amount, currency),
+                MinorUnit::new(amount)
+            );
+        }
+    }
+
+    #[test]
+    fn float_major_unit_round_trips() {
+        let convertor = FloatMajorUnitForConnector;
+        for (amount, currency) in [
+            (1099, Currency::USD),
+            (5000, Currency::JPY),
+            (12345, Currency::BHD),
+        ] {
+            assert_eq!(
+                round_trip(&convertor, amount, currency),
+                MinorUnit::new(amount)
+            );
+        }
+    }
+
+    #[test]
+    fn minor_unit_convertors_round_trip() {
+        assert_eq!(
+            round_trip(&MinorUnitForConnector, 1099, Currency::USD),
+            MinorUnit::new(1099)
+        );
+        assert_eq!(
+            round_trip(&StringMinorUnitForConnector, 1099, Currency::USD),
+            MinorUnit::new(1099)
+        );
+    }
+
+    #[test]
+    fn rejects_more_decimal_places_than_the_currency_has() {
+        let convertor = StringMajorUnitForConnector;
+        for (amount, currency) in [
+            ("10.999", Currency::USD),
+            ("10.5", Currency::JPY),
+            ("1.0995", Currency::KWD),
+        ] {
+            let result = convertor.convert_back(StringMajorUnit::new(amount.to_string()), currency);
+            assert!(matches!(
+                result.as_ref().map_err(|err| err.current_context()),
+                Err(AmountConversionError::AmountPrecisionExceeded(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn rejects_amounts_that_overflow() {
+        let result = StringMajorUnitForConnector.convert_back(
+            StringMajorUnit::new("92233720368547758.08".to_string()),
+            Currency::USD,
+        );
+        assert!(matches!(
+            result.as_ref().map_err(|err| err.current_context()),
+            Err(AmountConversionError::DecimalToI64ConversionFailure)
+        ));
+
+        let max = MinorUnit::new(i64::MAX);
+        assert_eq!(max.checked_add(MinorUnit::new(1)), None);
+        assert_eq!(
+            MinorUnit::new(i64::MIN).checked_sub(MinorUnit::new(1)),
+            None
+        );
+        assert_eq!(
+            MinorUnit::new(1099).checked_sub(MinorUnit::new(99)),
+            Some(MinorUnit::new(1000))
+        );
+    }
+}
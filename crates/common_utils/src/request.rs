@@ -21,9 +21,9 @@ pub type Headers = std::collections::HashSet<(String, Maskable<String>)>;
 pub enum Method {
     Get,
     Post,
-    // Put,
-    // Delete,
-    // Patch,
+    Put,
+    Patch,
+    Delete,
 }
 
 #[derive(Deserialize, Serialize, Debug)]
@@ -49,6 +49,10 @@ pub struct Request {
     pub certificate_key: Option<Secret<String>>,
     pub body: Option<RequestContent>,
     pub ca_certificate: Option<Secret<String>>,
+    /// Url encoded into the query string when the request is sent
+    pub query: Vec<(String, String)>,
+    /// Speak HTTP/2 without negotiating it through ALPN first
+    pub http2_prior_knowledge: bool,
 }
 
 impl std::fmt::Debug for RequestContent {
@@ -93,6 +97,8 @@ impl Request {
             certificate_key: None,
             body: None,
             ca_certificate: None,
+            query: Vec::new(),
+            http2_prior_knowledge: false,
         }
     }
 
@@ -113,7 +119,7 @@ impl Request {
     }
 
     pub fn add_certificate_key(&mut self, certificate_key: Option<Secret<String>>) {
-        self.certificate = certificate_key;
+        self.certificate_key = certificate_key;
     }
 }
 
@@ -126,6 +132,10 @@ pub struct RequestBuilder {
     pub certificate_key: Option<Secret<String>>,
     pub body: Option<RequestContent>,
     pub ca_certificate: Option<Secret<String>>,
+    /// Url encoded into the query string when the request is sent
+    pub query: Vec<(String, String)>,
+    /// Speak HTTP/2 without negotiating it through ALPN first
+    pub http2_prior_knowledge: bool,
 }
 
 impl RequestBuilder {
@@ -138,6 +148,8 @@ impl RequestBuilder {
             certificate_key: None,
             body: None,
             ca_certificate: None,
+            query: Vec::new(),
+            http2_prior_knowledge: false,
         }
     }
 
@@ -151,6 +163,18 @@ impl RequestBuilder {
         self
     }
 
+    pub fn query<K: Into<String>, V: Into<String>>(
+        mut self,
+        params: impl IntoIterator<Item = (K, V)>,
+    ) -> Self {
+        self.query.extend(
+            params
+                .into_iter()
+                .map(|(key, value)| (key.into(), value.into())),
+        );
+        self
+    }
+
     pub fn attach_default_headers(mut self) -> Self {
         self.headers.extend(default_request_headers());
         self
@@ -191,6 +215,11 @@ impl RequestBuilder {
         self
     }
 
+    pub fn http2_prior_knowledge(mut self, http2_prior_knowledge: bool) -> Self {
+        self.http2_prior_knowledge = http2_prior_knowledge;
+        self
+    }
+
     pub fn build(self) -> Request {
         Request {
             method: self.method,
@@ -200,6 +229,8 @@ impl RequestBuilder {
             certificate_key: self.certificate_key,
             body: self.body,
             ca_certificate: self.ca_certificate,
+            query: self.query,
+            http2_prior_knowledge: self.http2_prior_knowledge,
         }
     }
 }
@@ -1,22 +1,44 @@
 use std::fmt::Debug;
 pub mod transformers;
 use super::macros;
+use crate::types::{headers, ResponseRouterData};
 use common_utils::errors::CustomResult;
-use common_utils::request::RequestContent;
+use common_utils::ext_traits::BytesExt;
+use common_utils::money::{AmountConvertor, MinorUnit, MinorUnitForConnector};
+use common_utils::request::{Request, RequestContent};
 use domain_types::{
-    connector_flow::Authorize,
-    connector_types::{PaymentFlowData, PaymentsAuthorizeData, PaymentsResponseData},
+    connector_flow::{Authorize, Capture, PSync, RSync, Refund, Void},
+    connector_types::{
+        PaymentFlowData, PaymentVoidData, PaymentsAuthorizeData, PaymentsCaptureData,
+        PaymentsResponseData, PaymentsSyncData, RefundFlowData, RefundSyncData, RefundsData,
+        RefundsResponseData,
+    },
     errors::ConnectorError,
     payment_method::PaymentMethodDataTypes,
-    router_data_v2::RouterDataV2,
+    router_data::ErrorResponse,
+    router_data_v2::{ConnectorAuthType, RouterDataV2},
+    router_response_types::Response,
+    types::Connectors,
 };
+use error_stack::{report, ResultExt};
+use hyperswitch_masking::Maskable;
 use interfaces::{
     connector_integration_v2::ConnectorIntegrationV2,
-    connector_types::{ConnectorServiceTrait, PaymentAuthorizeV2},
+    connector_types::{
+        ConnectorServiceTrait, PaymentAuthorizeV2, PaymentCaptureV2, PaymentSyncV2, PaymentVoidV2,
+        RefundSyncV2, RefundV2,
+    },
 };
 use serde::Serialize;
 use std::marker::PhantomData;
-use transformers::AdyenPaymentRequest;
+use transformers::{
+    AdyenAuthType, AdyenCancelRequest, AdyenCancelResponse, AdyenCaptureRequest,
+    AdyenCaptureResponse, AdyenErrorResponse, AdyenPaymentRequest, AdyenPaymentResponse,
+    AdyenRedirectRequest, AdyenRefundRequest, AdyenRefundResponse,
+};
+
+pub(crate) const ADYEN_API_VERSION: &str = "v68";
+
 impl<T: PaymentMethodDataTypes + Debug + Sync + Send + Serialize + 'static> ConnectorServiceTrait<T>
     for Adyen<T>
 {
@@ -27,6 +49,28 @@ impl<T: PaymentMethodDataTypes + Debug + Sync + Send + Serialize + 'static> Paym
 {
 }
 
+impl<T: PaymentMethodDataTypes + Debug + Sync + Send + Serialize + 'static> PaymentSyncV2
+    for Adyen<T>
+{
+}
+
+impl<T: PaymentMethodDataTypes + Debug + Sync + Send + Serialize + 'static> PaymentCaptureV2
+    for Adyen<T>
+{
+}
+
+impl<T: PaymentMethodDataTypes + Debug + Sync + Send + Serialize + 'static> PaymentVoidV2
+    for Adyen<T>
+{
+}
+
+impl<T: PaymentMethodDataTypes + Debug + Sync + Send + Serialize + 'static> RefundV2 for Adyen<T> {}
+
+impl<T: PaymentMethodDataTypes + Debug + Sync + Send + Serialize + 'static> RefundSyncV2
+    for Adyen<T>
+{
+}
+
 // macros::create_all_prerequisites!(
 //     connector_name:Adyen,
 //     generic_type: T,
@@ -50,7 +94,7 @@ pub struct Adyen<
 > {
     authorize: &'static (dyn macros::BridgeRequestResponse<
         RequestBody = AdyenPaymentRequest<T>,
-        ResponseBody = String,
+        ResponseBody = AdyenPaymentResponse,
         ConnectorInputData = AdyenRouterData<
             RouterDataV2<
                 Authorize,
@@ -61,6 +105,39 @@ pub struct Adyen<
             T,
         >,
     >),
+    psync: &'static (dyn macros::BridgeRequestResponse<
+        RequestBody = AdyenRedirectRequest,
+        ResponseBody = AdyenPaymentResponse,
+        ConnectorInputData = AdyenRouterData<
+            RouterDataV2<PSync, PaymentFlowData, PaymentsSyncData, PaymentsResponseData>,
+            T,
+        >,
+    >),
+    capture: &'static (dyn macros::BridgeRequestResponse<
+        RequestBody = AdyenCaptureRequest,
+        ResponseBody = AdyenCaptureResponse,
+        ConnectorInputData = AdyenRouterData<
+            RouterDataV2<Capture, PaymentFlowData, PaymentsCaptureData, PaymentsResponseData>,
+            T,
+        >,
+    >),
+    void: &'static (dyn macros::BridgeRequestResponse<
+        RequestBody = AdyenCancelRequest,
+        ResponseBody = AdyenCancelResponse,
+        ConnectorInputData = AdyenRouterData<
+            RouterDataV2<Void, PaymentFlowData, PaymentVoidData, PaymentsResponseData>,
+            T,
+        >,
+    >),
+    refund: &'static (dyn macros::BridgeRequestResponse<
+        RequestBody = AdyenRefundRequest,
+        ResponseBody = AdyenRefundResponse,
+        ConnectorInputData = AdyenRouterData<
+            RouterDataV2<Refund, RefundFlowData, RefundsData, RefundsResponseData>,
+            T,
+        >,
+    >),
+    amount_converter: &'static (dyn AmountConvertor<Output = MinorUnit> + Sync),
 }
 pub struct AdyenRouterData<
     RD: macros::FlowTypes,
@@ -77,7 +154,21 @@ pub struct AdyenRouterData<
 
 pub struct AdyenPaymentRequestTemplating;
 
-pub struct StringTemplating;
+pub struct AdyenRedirectRequestTemplating;
+
+pub struct AdyenCaptureRequestTemplating;
+
+pub struct AdyenCaptureResponseTemplating;
+
+pub struct AdyenCancelRequestTemplating;
+
+pub struct AdyenCancelResponseTemplating;
+
+pub struct AdyenRefundRequestTemplating;
+
+pub struct AdyenRefundResponseTemplating;
+
+pub struct AdyenPaymentResponseTemplating;
 
 impl<
         RD: macros::FlowTypes,
@@ -103,16 +194,86 @@ impl<
             + 'static
             + serde::Serialize,
     > macros::BridgeRequestResponse
-    for macros::Bridge<AdyenPaymentRequestTemplating, StringTemplating, T>
+    for macros::Bridge<AdyenPaymentRequestTemplating, AdyenPaymentResponseTemplating, T>
 {
     type RequestBody = AdyenPaymentRequest<T>;
-    type ResponseBody = String;
+    type ResponseBody = AdyenPaymentResponse;
     type ConnectorInputData = AdyenRouterData<
         RouterDataV2<Authorize, PaymentFlowData, PaymentsAuthorizeData<T>, PaymentsResponseData>,
         T,
     >;
 }
 
+impl<
+        T: PaymentMethodDataTypes
+            + std::fmt::Debug
+            + std::marker::Sync
+            + std::marker::Send
+            + 'static
+            + serde::Serialize,
+    > macros::BridgeRequestResponse
+    for macros::Bridge<AdyenRedirectRequestTemplating, AdyenPaymentResponseTemplating, T>
+{
+    type RequestBody = AdyenRedirectRequest;
+    type ResponseBody = AdyenPaymentResponse;
+    type ConnectorInputData = AdyenRouterData<
+        RouterDataV2<PSync, PaymentFlowData, PaymentsSyncData, PaymentsResponseData>,
+        T,
+    >;
+}
+
+impl<
+        T: PaymentMethodDataTypes
+            + std::fmt::Debug
+            + std::marker::Sync
+            + std::marker::Send
+            + 'static
+            + serde::Serialize,
+    > macros::BridgeRequestResponse
+    for macros::Bridge<AdyenCaptureRequestTemplating, AdyenCaptureResponseTemplating, T>
+{
+    type RequestBody = AdyenCaptureRequest;
+    type ResponseBody = AdyenCaptureResponse;
+    type ConnectorInputData = AdyenRouterData<
+        RouterDataV2<Capture, PaymentFlowData, PaymentsCaptureData, PaymentsResponseData>,
+        T,
+    >;
+}
+
+impl<
+        T: PaymentMethodDataTypes
+            + std::fmt::Debug
+            + std::marker::Sync
+            + std::marker::Send
+            + 'static
+            + serde::Serialize,
+    > macros::BridgeRequestResponse
+    for macros::Bridge<AdyenCancelRequestTemplating, AdyenCancelResponseTemplating, T>
+{
+    type RequestBody = AdyenCancelRequest;
+    type ResponseBody = AdyenCancelResponse;
+    type ConnectorInputData = AdyenRouterData<
+        RouterDataV2<Void, PaymentFlowData, PaymentVoidData, PaymentsResponseData>,
+        T,
+    >;
+}
+
+impl<
+        T: PaymentMethodDataTypes
+            + std::fmt::Debug
+            + std::marker::Sync
+            + std::marker::Send
+            + 'static
+            + serde::Serialize,
+    > macros::BridgeRequestResponse
+    for macros::Bridge<AdyenRefundRequestTemplating, AdyenRefundResponseTemplating, T>
+{
+    type RequestBody = AdyenRefundRequest;
+    type ResponseBody = AdyenRefundResponse;
+    type ConnectorInputData =
+        AdyenRouterData<RouterDataV2<Refund, RefundFlowData, RefundsData, RefundsResponseData>, T>;
+}
+
 impl<
         T: PaymentMethodDataTypes
             + std::fmt::Debug
@@ -124,11 +285,70 @@ impl<
 {
     pub const fn new() -> &'static Self {
         &Self {
-            authorize: &macros::Bridge::<AdyenPaymentRequestTemplating, StringTemplating, T>(
+            authorize: &macros::Bridge::<
+                AdyenPaymentRequestTemplating,
+                AdyenPaymentResponseTemplating,
+                T,
+            >(PhantomData),
+            psync: &macros::Bridge::<
+                AdyenRedirectRequestTemplating,
+                AdyenPaymentResponseTemplating,
+                T,
+            >(PhantomData),
+            capture: &macros::Bridge::<
+                AdyenCaptureRequestTemplating,
+                AdyenCaptureResponseTemplating,
+                T,
+            >(PhantomData),
+            void: &macros::Bridge::<AdyenCancelRequestTemplating, AdyenCancelResponseTemplating, T>(
+                PhantomData,
+            ),
+            refund: &macros::Bridge::<AdyenRefundRequestTemplating, AdyenRefundResponseTemplating, T>(
                 PhantomData,
             ),
+            amount_converter: &MinorUnitForConnector,
         }
     }
+
+    fn build_headers(
+        &self,
+        auth_type: &ConnectorAuthType,
+    ) -> CustomResult<Vec<(String, Maskable<String>)>, ConnectorError> {
+        let auth = AdyenAuthType::try_from(auth_type)?;
+        Ok(vec![
+            (
+                headers::CONTENT_TYPE.to_string(),
+                "application/json".to_string().into(),
+            ),
+            (
+                headers::X_API_KEY.to_string(),
+                Maskable::Masked(auth.api_key),
+            ),
+        ])
+    }
+
+    fn http2_prior_knowledge(&self, connectors: &Connectors) -> bool {
+        connectors.adyen.http2_prior_knowledge
+    }
+
+    fn build_error_response(&self, res: Response) -> CustomResult<ErrorResponse, ConnectorError> {
+        let response: AdyenErrorResponse = res
+            .response
+            .parse_struct("AdyenErrorResponse")
+            .change_context(ConnectorError::ResponseDeserializationFailed)?;
+
+        Ok(ErrorResponse {
+            status_code: res.status_code,
+            code: response.error_code,
+            message: response.message.clone(),
+            reason: Some(response.message),
+            attempt_status: None,
+            connector_transaction_id: response.psp_reference,
+            network_decline_code: None,
+            network_advice_code: None,
+            network_error_message: None,
+        })
+    }
 }
 
 impl<T: PaymentMethodDataTypes + Debug + Sync + Send + Serialize + 'static>
@@ -139,6 +359,18 @@ impl<T: PaymentMethodDataTypes + Debug + Sync + Send + Serialize + 'static>
         PaymentsResponseData,
     > for Adyen<T>
 {
+    fn get_headers(
+        &self,
+        req: &RouterDataV2<
+            Authorize,
+            PaymentFlowData,
+            PaymentsAuthorizeData<T>,
+            PaymentsResponseData,
+        >,
+    ) -> CustomResult<Vec<(String, Maskable<String>)>, ConnectorError> {
+        self.build_headers(&req.connector_auth_type)
+    }
+
     fn get_url(
         &self,
         req: &RouterDataV2<
@@ -148,8 +380,22 @@ impl<T: PaymentMethodDataTypes + Debug + Sync + Send + Serialize + 'static>
             PaymentsResponseData,
         >,
     ) -> CustomResult<String, ConnectorError> {
-        let url = req.resource_common_data.connectors.adyen.base_url.clone();
-        Ok(url)
+        Ok(format!(
+            "{}{}/payments",
+            req.resource_common_data.connectors.adyen.base_url, ADYEN_API_VERSION
+        ))
+    }
+
+    fn get_http2_prior_knowledge(
+        &self,
+        req: &RouterDataV2<
+            Authorize,
+            PaymentFlowData,
+            PaymentsAuthorizeData<T>,
+            PaymentsResponseData,
+        >,
+    ) -> bool {
+        self.http2_prior_knowledge(&req.resource_common_data.connectors)
     }
 
     fn get_request_body(
@@ -169,4 +415,302 @@ impl<T: PaymentMethodDataTypes + Debug + Sync + Send + Serialize + 'static>
         let request = bridge.request_body(input_data)?;
         Ok(Some(RequestContent::Json(Box::new(request))))
     }
+
+    fn handle_response_v2(
+        &self,
+        data: &RouterDataV2<
+            Authorize,
+            PaymentFlowData,
+            PaymentsAuthorizeData<T>,
+            PaymentsResponseData,
+        >,
+        res: Response,
+    ) -> CustomResult<
+        RouterDataV2<Authorize, PaymentFlowData, PaymentsAuthorizeData<T>, PaymentsResponseData>,
+        ConnectorError,
+    > {
+        let bridge = self.authorize;
+        let response = bridge.response(res.response)?;
+        bridge.router_data(ResponseRouterData {
+            response,
+            router_data: data.clone(),
+            http_code: res.status_code,
+        })
+    }
+
+    fn get_error_response_v2(&self, res: Response) -> CustomResult<ErrorResponse, ConnectorError> {
+        self.build_error_response(res)
+    }
+}
+
+impl<T: PaymentMethodDataTypes + Debug + Sync + Send + Serialize + 'static>
+    ConnectorIntegrationV2<PSync, PaymentFlowData, PaymentsSyncData, PaymentsResponseData>
+    for Adyen<T>
+{
+    fn get_headers(
+        &self,
+        req: &RouterDataV2<PSync, PaymentFlowData, PaymentsSyncData, PaymentsResponseData>,
+    ) -> CustomResult<Vec<(String, Maskable<String>)>, ConnectorError> {
+        self.build_headers(&req.connector_auth_type)
+    }
+
+    fn get_url(
+        &self,
+        req: &RouterDataV2<PSync, PaymentFlowData, PaymentsSyncData, PaymentsResponseData>,
+    ) -> CustomResult<String, ConnectorError> {
+        Ok(format!(
+            "{}{}/payments/details",
+            req.resource_common_data.connectors.adyen.base_url, ADYEN_API_VERSION
+        ))
+    }
+
+    fn get_http2_prior_knowledge(
+        &self,
+        req: &RouterDataV2<PSync, PaymentFlowData, PaymentsSyncData, PaymentsResponseData>,
+    ) -> bool {
+        self.http2_prior_knowledge(&req.resource_common_data.connectors)
+    }
+
+    fn get_request_body(
+        &self,
+        req: &RouterDataV2<PSync, PaymentFlowData, PaymentsSyncData, PaymentsResponseData>,
+    ) -> CustomResult<Option<RequestContent>, ConnectorError> {
+        let bridge = self.psync;
+        let input_data = AdyenRouterData {
+            connector: self.to_owned(),
+            router_data: req.clone(),
+        };
+        let request = bridge.request_body(input_data)?;
+        Ok(Some(RequestContent::Json(Box::new(request))))
+    }
+
+    fn handle_response_v2(
+        &self,
+        data: &RouterDataV2<PSync, PaymentFlowData, PaymentsSyncData, PaymentsResponseData>,
+        res: Response,
+    ) -> CustomResult<
+        RouterDataV2<PSync, PaymentFlowData, PaymentsSyncData, PaymentsResponseData>,
+        ConnectorError,
+    > {
+        let bridge = self.psync;
+        let response = bridge.response(res.response)?;
+        bridge.router_data(ResponseRouterData {
+            response,
+            router_data: data.clone(),
+            http_code: res.status_code,
+        })
+    }
+
+    fn get_error_response_v2(&self, res: Response) -> CustomResult<ErrorResponse, ConnectorError> {
+        self.build_error_response(res)
+    }
+}
+
+impl<T: PaymentMethodDataTypes + Debug + Sync + Send + Serialize + 'static>
+    ConnectorIntegrationV2<Capture, PaymentFlowData, PaymentsCaptureData, PaymentsResponseData>
+    for Adyen<T>
+{
+    fn get_headers(
+        &self,
+        req: &RouterDataV2<Capture, PaymentFlowData, PaymentsCaptureData, PaymentsResponseData>,
+    ) -> CustomResult<Vec<(String, Maskable<String>)>, ConnectorError> {
+        self.build_headers(&req.connector_auth_type)
+    }
+
+    fn get_url(
+        &self,
+        req: &RouterDataV2<Capture, PaymentFlowData, PaymentsCaptureData, PaymentsResponseData>,
+    ) -> CustomResult<String, ConnectorError> {
+        Ok(format!(
+            "{}{}/payments/{}/captures",
+            req.resource_common_data.connectors.adyen.base_url,
+            ADYEN_API_VERSION,
+            req.request.connector_transaction_id
+        ))
+    }
+
+    fn get_http2_prior_knowledge(
+        &self,
+        req: &RouterDataV2<Capture, PaymentFlowData, PaymentsCaptureData, PaymentsResponseData>,
+    ) -> bool {
+        self.http2_prior_knowledge(&req.resource_common_data.connectors)
+    }
+
+    fn get_request_body(
+        &self,
+        req: &RouterDataV2<Capture, PaymentFlowData, PaymentsCaptureData, PaymentsResponseData>,
+    ) -> CustomResult<Option<RequestContent>, ConnectorError> {
+        let bridge = self.capture;
+        let input_data = AdyenRouterData {
+            connector: self.to_owned(),
+            router_data: req.clone(),
+        };
+        let request = bridge.request_body(input_data)?;
+        Ok(Some(RequestContent::Json(Box::new(request))))
+    }
+
+    fn handle_response_v2(
+        &self,
+        data: &RouterDataV2<Capture, PaymentFlowData, PaymentsCaptureData, PaymentsResponseData>,
+        res: Response,
+    ) -> CustomResult<
+        RouterDataV2<Capture, PaymentFlowData, PaymentsCaptureData, PaymentsResponseData>,
+        ConnectorError,
+    > {
+        let bridge = self.capture;
+        let response = bridge.response(res.response)?;
+        bridge.router_data(ResponseRouterData {
+            response,
+            router_data: data.clone(),
+            http_code: res.status_code,
+        })
+    }
+
+    fn get_error_response_v2(&self, res: Response) -> CustomResult<ErrorResponse, ConnectorError> {
+        self.build_error_response(res)
+    }
+}
+
+impl<T: PaymentMethodDataTypes + Debug + Sync + Send + Serialize + 'static>
+    ConnectorIntegrationV2<Void, PaymentFlowData, PaymentVoidData, PaymentsResponseData>
+    for Adyen<T>
+{
+    fn get_headers(
+        &self,
+        req: &RouterDataV2<Void, PaymentFlowData, PaymentVoidData, PaymentsResponseData>,
+    ) -> CustomResult<Vec<(String, Maskable<String>)>, ConnectorError> {
+        self.build_headers(&req.connector_auth_type)
+    }
+
+    fn get_url(
+        &self,
+        req: &RouterDataV2<Void, PaymentFlowData, PaymentVoidData, PaymentsResponseData>,
+    ) -> CustomResult<String, ConnectorError> {
+        Ok(format!(
+            "{}{}/payments/{}/cancels",
+            req.resource_common_data.connectors.adyen.base_url,
+            ADYEN_API_VERSION,
+            req.request.connector_transaction_id
+        ))
+    }
+
+    fn get_http2_prior_knowledge(
+        &self,
+        req: &RouterDataV2<Void, PaymentFlowData, PaymentVoidData, PaymentsResponseData>,
+    ) -> bool {
+        self.http2_prior_knowledge(&req.resource_common_data.connectors)
+    }
+
+    fn get_request_body(
+        &self,
+        req: &RouterDataV2<Void, PaymentFlowData, PaymentVoidData, PaymentsResponseData>,
+    ) -> CustomResult<Option<RequestContent>, ConnectorError> {
+        let bridge = self.void;
+        let input_data = AdyenRouterData {
+            connector: self.to_owned(),
+            router_data: req.clone(),
+        };
+        let request = bridge.request_body(input_data)?;
+        Ok(Some(RequestContent::Json(Box::new(request))))
+    }
+
+    fn handle_response_v2(
+        &self,
+        data: &RouterDataV2<Void, PaymentFlowData, PaymentVoidData, PaymentsResponseData>,
+        res: Response,
+    ) -> CustomResult<
+        RouterDataV2<Void, PaymentFlowData, PaymentVoidData, PaymentsResponseData>,
+        ConnectorError,
+    > {
+        let bridge = self.void;
+        let response = bridge.response(res.response)?;
+        bridge.router_data(ResponseRouterData {
+            response,
+            router_data: data.clone(),
+            http_code: res.status_code,
+        })
+    }
+
+    fn get_error_response_v2(&self, res: Response) -> CustomResult<ErrorResponse, ConnectorError> {
+        self.build_error_response(res)
+    }
+}
+
+impl<T: PaymentMethodDataTypes + Debug + Sync + Send + Serialize + 'static>
+    ConnectorIntegrationV2<Refund, RefundFlowData, RefundsData, RefundsResponseData> for Adyen<T>
+{
+    fn get_headers(
+        &self,
+        req: &RouterDataV2<Refund, RefundFlowData, RefundsData, RefundsResponseData>,
+    ) -> CustomResult<Vec<(String, Maskable<String>)>, ConnectorError> {
+        self.build_headers(&req.connector_auth_type)
+    }
+
+    fn get_url(
+        &self,
+        req: &RouterDataV2<Refund, RefundFlowData, RefundsData, RefundsResponseData>,
+    ) -> CustomResult<String, ConnectorError> {
+        Ok(format!(
+            "{}{}/payments/{}/refunds",
+            req.resource_common_data.connectors.adyen.base_url,
+            ADYEN_API_VERSION,
+            req.request.connector_transaction_id
+        ))
+    }
+
+    fn get_http2_prior_knowledge(
+        &self,
+        req: &RouterDataV2<Refund, RefundFlowData, RefundsData, RefundsResponseData>,
+    ) -> bool {
+        self.http2_prior_knowledge(&req.resource_common_data.connectors)
+    }
+
+    fn get_request_body(
+        &self,
+        req: &RouterDataV2<Refund, RefundFlowData, RefundsData, RefundsResponseData>,
+    ) -> CustomResult<Option<RequestContent>, ConnectorError> {
+        let bridge = self.refund;
+        let input_data = AdyenRouterData {
+            connector: self.to_owned(),
+            router_data: req.clone(),
+        };
+        let request = bridge.request_body(input_data)?;
+        Ok(Some(RequestContent::Json(Box::new(request))))
+    }
+
+    fn handle_response_v2(
+        &self,
+        data: &RouterDataV2<Refund, RefundFlowData, RefundsData, RefundsResponseData>,
+        res: Response,
+    ) -> CustomResult<
+        RouterDataV2<Refund, RefundFlowData, RefundsData, RefundsResponseData>,
+        ConnectorError,
+    > {
+        let bridge = self.refund;
+        let response = bridge.response(res.response)?;
+        bridge.router_data(ResponseRouterData {
+            response,
+            router_data: data.clone(),
+            http_code: res.status_code,
+        })
+    }
+
+    fn get_error_response_v2(&self, res: Response) -> CustomResult<ErrorResponse, ConnectorError> {
+        self.build_error_response(res)
+    }
+}
+
+impl<T: PaymentMethodDataTypes + Debug + Sync + Send + Serialize + 'static>
+    ConnectorIntegrationV2<RSync, RefundFlowData, RefundSyncData, RefundsResponseData>
+    for Adyen<T>
+{
+    /// Adyen has no refund lookup API, refund outcomes are only delivered through webhooks
+    fn build_request_v2(
+        &self,
+        _req: &RouterDataV2<RSync, RefundFlowData, RefundSyncData, RefundsResponseData>,
+    ) -> CustomResult<Option<Request>, ConnectorError> {
+        Err(report!(ConnectorError::NotImplemented(
+            "Refund sync for Adyen".to_string()
+        )))
+    }
 }
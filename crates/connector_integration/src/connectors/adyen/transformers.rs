@@ -1,16 +1,32 @@
 use super::AdyenRouterData;
-use domain_types::connector_flow::Authorize;
-use domain_types::connector_types::{PaymentFlowData, PaymentsAuthorizeData, PaymentsResponseData};
+use crate::types::ResponseRouterData;
+use crate::utils::convert_amount;
+use common_enums::{AttemptStatus, CaptureMethod, CardNetwork, Currency, FutureUsage};
+use common_utils::money::MinorUnit;
+use common_utils::request::Method;
+use domain_types::connector_flow::{Authorize, Capture, PSync, Refund, Void};
+use domain_types::connector_types::{
+    Address, BrowserInformation, PaymentFlowData, PaymentVoidData, PaymentsAuthorizeData,
+    PaymentsCaptureData, PaymentsResponseData, PaymentsSyncData, RefundFlowData, RefundsData,
+    RefundsResponseData, ResponseId,
+};
 use domain_types::errors;
 use domain_types::payment_method::{
     Card, PaymentMethodData, PaymentMethodDataTypes, RawCardNumber,
 };
-use domain_types::router_data_v2::RouterDataV2;
+use domain_types::router_data::ErrorResponse;
+use domain_types::router_data_v2::{ConnectorAuthType, RouterDataV2};
+use domain_types::router_response_types::RedirectForm;
 use hyperswitch_masking::Secret;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::str::FromStr;
 type Error = error_stack::Report<domain_types::errors::ConnectorError>;
+
+const NO_ERROR_CODE: &str = "No error code";
+const NO_ERROR_MESSAGE: &str = "No error message";
 #[derive(Debug, Clone, Serialize)]
-// #[serde(rename_all = "camelCase")]
+#[serde(rename_all = "camelCase")]
 pub struct AdyenCard<
     T: PaymentMethodDataTypes
         + std::fmt::Debug
@@ -20,10 +36,15 @@ pub struct AdyenCard<
         + 'static,
 > {
     number: RawCardNumber<T>,
-    card_holder_name: Option<Secret<String>>,
+    expiry_month: Secret<String>,
+    expiry_year: Secret<String>,
+    cvc: Option<Secret<String>>,
+    holder_name: Option<Secret<String>>,
+    brand: Option<CardBrand>,
 }
 
 #[derive(Debug, Clone, Serialize)]
+#[serde(tag = "type")]
 pub enum AdyenPaymentMethod<
     T: PaymentMethodDataTypes
         + std::fmt::Debug
@@ -32,10 +53,12 @@ pub enum AdyenPaymentMethod<
         + Serialize
         + 'static,
 > {
+    #[serde(rename = "scheme")]
     AdyenCard(Box<AdyenCard<T>>),
 }
 
 #[derive(Debug, Clone, Serialize)]
+#[serde(untagged)]
 pub enum PaymentMethod<
     T: PaymentMethodDataTypes
         + std::fmt::Debug
@@ -48,6 +71,7 @@ pub enum PaymentMethod<
 }
 
 #[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct AdyenPaymentRequest<
     T: PaymentMethodDataTypes
         + std::fmt::Debug
@@ -56,12 +80,242 @@ pub struct AdyenPaymentRequest<
         + Serialize
         + 'static,
 > {
-    amount: i64,
-    merchant_account: String,
+    amount: Amount,
+    merchant_account: Secret<String>,
     payment_method: PaymentMethod<T>,
+    reference: String,
+    return_url: String,
+    shopper_interaction: AdyenShopperInteraction,
+    recurring_processing_model: Option<AdyenRecurringModel>,
+    shopper_reference: Option<String>,
+    store_payment_method: Option<bool>,
+    browser_info: Option<AdyenBrowserInfo>,
+    billing_address: Option<AdyenAddress>,
+    additional_data: Option<AdyenAdditionalData>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub enum AdyenShopperInteraction {
+    Ecommerce,
+    #[serde(rename = "ContAuth")]
+    ContinuedAuthentication,
+    Moto,
+    #[serde(rename = "POS")]
+    Pos,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub enum AdyenRecurringModel {
+    UnscheduledCardOnFile,
+    CardOnFile,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenBrowserInfo {
+    user_agent: String,
+    accept_header: String,
+    language: String,
+    color_depth: u8,
+    screen_height: u32,
+    screen_width: u32,
+    time_zone_offset: i32,
+    java_enabled: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenAddress {
+    city: String,
+    country: String,
+    house_number_or_name: Secret<String>,
+    postal_code: Secret<String>,
+    state_or_province: Option<Secret<String>>,
+    street: Option<Secret<String>>,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenAdditionalData {
+    /// Holds the authorization until a separate capture request is sent
+    manual_capture: Option<String>,
+}
+
+pub struct AdyenAuthType {
+    pub(super) api_key: Secret<String>,
+    pub(super) merchant_account: Secret<String>,
+}
+
+impl TryFrom<&ConnectorAuthType> for AdyenAuthType {
+    type Error = Error;
+    fn try_from(auth_type: &ConnectorAuthType) -> Result<Self, Self::Error> {
+        match auth_type {
+            ConnectorAuthType::BodyKey { api_key, key1 } => Ok(Self {
+                api_key: api_key.to_owned(),
+                merchant_account: key1.to_owned(),
+            }),
+            _ => Err(errors::ConnectorError::FailedToObtainAuthType.into()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Amount {
+    pub currency: Currency,
+    pub value: MinorUnit,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AdyenRedirectRequest {
+    details: AdyenRedirectionDetails,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenRedirectionDetails {
+    redirect_result: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenCaptureRequest {
+    merchant_account: Secret<String>,
+    amount: Amount,
+    reference: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenCancelRequest {
+    merchant_account: Secret<String>,
+    reference: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenRefundRequest {
+    merchant_account: Secret<String>,
+    amount: Amount,
+    merchant_refund_reason: Option<AdyenRefundRequestReason>,
+    reference: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum AdyenRefundRequestReason {
+    Fraud,
+    #[serde(rename = "CUSTOMER REQUEST")]
+    CustomerRequest,
+    Return,
+    Duplicate,
+    Other,
+}
+
+impl FromStr for AdyenRefundRequestReason {
+    type Err = error_stack::Report<errors::ConnectorError>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_uppercase().as_str() {
+            "FRAUD" => Ok(Self::Fraud),
+            "CUSTOMER REQUEST" | "CUSTOMER_REQUEST" => Ok(Self::CustomerRequest),
+            "RETURN" => Ok(Self::Return),
+            "DUPLICATE" => Ok(Self::Duplicate),
+            _ => Ok(Self::Other),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenErrorResponse {
+    pub status: i32,
+    pub error_code: String,
+    pub message: String,
+    pub error_type: String,
+    pub psp_reference: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub enum AdyenStatus {
+    AuthenticationFinished,
+    AuthenticationNotRequired,
+    Authorised,
+    Cancelled,
+    ChallengeShopper,
+    Error,
+    Pending,
+    Received,
+    RedirectShopper,
+    Refused,
+    PresentToShopper,
+    IdentifyShopper,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenPaymentResponse {
+    result_code: AdyenStatus,
+    psp_reference: Option<String>,
+    merchant_reference: Option<String>,
+    refusal_reason: Option<String>,
+    refusal_reason_code: Option<String>,
+    action: Option<AdyenRedirectAction>,
+    additional_data: Option<AdditionalData>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenRedirectAction {
+    #[serde(rename = "type")]
+    type_of_response: String,
+    payment_method_type: Option<String>,
+    /// only sent with `redirect` actions, `threeDS2`, `sdk` and `await` actions carry none
+    url: Option<String>,
+    method: Option<Method>,
+    data: Option<HashMap<String, String>>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdditionalData {
+    refusal_reason_raw: Option<String>,
+    refusal_code_raw: Option<String>,
+    merchant_advice_code: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenCaptureResponse {
+    merchant_account: String,
+    payment_psp_reference: String,
+    psp_reference: String,
+    reference: String,
+    status: String,
+    amount: Amount,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenCancelResponse {
+    merchant_account: String,
+    payment_psp_reference: String,
+    psp_reference: String,
+    reference: String,
+    status: String,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenRefundResponse {
+    merchant_account: String,
+    payment_psp_reference: String,
+    psp_reference: String,
+    reference: String,
+    status: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum CardBrand {
     Visa,
     MC,
@@ -79,6 +333,164 @@ pub enum CardBrand {
     Nyce,
 }
 
+impl From<&CardNetwork> for CardBrand {
+    fn from(card_network: &CardNetwork) -> Self {
+        match card_network {
+            CardNetwork::Visa => Self::Visa,
+            CardNetwork::Mastercard => Self::MC,
+            CardNetwork::AmericanExpress => Self::Amex,
+            CardNetwork::JCB => Self::Jcb,
+            CardNetwork::DinersClub => Self::Diners,
+            CardNetwork::Discover => Self::Discover,
+            CardNetwork::CartesBancaires => Self::Cartebancaire,
+            CardNetwork::UnionPay => Self::Cup,
+            CardNetwork::Maestro => Self::Maestro,
+            CardNetwork::RuPay => Self::Rupay,
+        }
+    }
+}
+
+impl TryFrom<&BrowserInformation> for AdyenBrowserInfo {
+    type Error = Error;
+    fn try_from(browser_info: &BrowserInformation) -> Result<Self, Self::Error> {
+        Ok(Self {
+            user_agent: browser_info.user_agent.clone().ok_or(
+                errors::ConnectorError::MissingRequiredField {
+                    field_name: "browser_info.user_agent",
+                },
+            )?,
+            accept_header: browser_info.accept_header.clone().ok_or(
+                errors::ConnectorError::MissingRequiredField {
+                    field_name: "browser_info.accept_header",
+                },
+            )?,
+            language: browser_info.language.clone().ok_or(
+                errors::ConnectorError::MissingRequiredField {
+                    field_name: "browser_info.language",
+                },
+            )?,
+            color_depth: browser_info.color_depth.ok_or(
+                errors::ConnectorError::MissingRequiredField {
+                    field_name: "browser_info.color_depth",
+                },
+            )?,
+            screen_height: browser_info.screen_height.ok_or(
+                errors::ConnectorError::MissingRequiredField {
+                    field_name: "browser_info.screen_height",
+                },
+            )?,
+            screen_width: browser_info.screen_width.ok_or(
+                errors::ConnectorError::MissingRequiredField {
+                    field_name: "browser_info.screen_width",
+                },
+            )?,
+            time_zone_offset: browser_info.time_zone.ok_or(
+                errors::ConnectorError::MissingRequiredField {
+                    field_name: "browser_info.time_zone",
+                },
+            )?,
+            java_enabled: browser_info.java_enabled.ok_or(
+                errors::ConnectorError::MissingRequiredField {
+                    field_name: "browser_info.java_enabled",
+                },
+            )?,
+        })
+    }
+}
+
+impl TryFrom<&Address> for AdyenAddress {
+    type Error = Error;
+    fn try_from(address: &Address) -> Result<Self, Self::Error> {
+        Ok(Self {
+            city: address
+                .city
+                .clone()
+                .ok_or(errors::ConnectorError::MissingRequiredField {
+                    field_name: "billing_address.city",
+                })?,
+            country: address.country.clone().ok_or(
+                errors::ConnectorError::MissingRequiredField {
+                    field_name: "billing_address.country",
+                },
+            )?,
+            house_number_or_name: address.line1.clone().ok_or(
+                errors::ConnectorError::MissingRequiredField {
+                    field_name: "billing_address.line1",
+                },
+            )?,
+            postal_code: address.zip.clone().ok_or(
+                errors::ConnectorError::MissingRequiredField {
+                    field_name: "billing_address.zip",
+                },
+            )?,
+            state_or_province: address.state.clone(),
+            street: address.line2.clone(),
+        })
+    }
+}
+
+/// Off-session payments and payments stored for later use are sent as card on file
+fn get_recurring_processing_model<T: PaymentMethodDataTypes>(
+    request: &PaymentsAuthorizeData<T>,
+) -> (Option<AdyenRecurringModel>, Option<bool>, Option<String>) {
+    match (request.setup_future_usage, request.off_session) {
+        (Some(FutureUsage::OffSession), _) => (
+            Some(AdyenRecurringModel::UnscheduledCardOnFile),
+            Some(true),
+            request.customer_id.clone(),
+        ),
+        (Some(FutureUsage::OnSession), _) => (
+            Some(AdyenRecurringModel::CardOnFile),
+            Some(true),
+            request.customer_id.clone(),
+        ),
+        (None, Some(true)) => (
+            Some(AdyenRecurringModel::UnscheduledCardOnFile),
+            None,
+            request.customer_id.clone(),
+        ),
+        (None, _) => (None, None, None),
+    }
+}
+
+fn get_additional_data(capture_method: Option<CaptureMethod>) -> Option<AdyenAdditionalData> {
+    match capture_method {
+        Some(CaptureMethod::Manual) | Some(CaptureMethod::ManualMultiple) => {
+            Some(AdyenAdditionalData {
+                manual_capture: Some("true".to_string()),
+            })
+        }
+        _ => None,
+    }
+}
+
+/// Authorised is kept as `Authorized`, the amount is only charged once a capture is confirmed
+fn get_adyen_payment_status(adyen_status: &AdyenStatus) -> AttemptStatus {
+    match adyen_status {
+        AdyenStatus::AuthenticationFinished => AttemptStatus::AuthenticationSuccessful,
+        AdyenStatus::AuthenticationNotRequired | AdyenStatus::Received => AttemptStatus::Pending,
+        AdyenStatus::Authorised => AttemptStatus::Authorized,
+        AdyenStatus::ChallengeShopper
+        | AdyenStatus::RedirectShopper
+        | AdyenStatus::PresentToShopper
+        | AdyenStatus::IdentifyShopper => AttemptStatus::AuthenticationPending,
+        AdyenStatus::Error | AdyenStatus::Refused => AttemptStatus::Failure,
+        AdyenStatus::Cancelled => AttemptStatus::Voided,
+        AdyenStatus::Pending => AttemptStatus::Pending,
+    }
+}
+
+impl AdyenRedirectAction {
+    /// The form the shopper has to be sent to, if the action is a redirect
+    fn into_redirect_form(self) -> Option<RedirectForm> {
+        self.url.map(|endpoint| RedirectForm::Form {
+            endpoint,
+            method: self.method.unwrap_or(Method::Get),
+            form_fields: self.data.unwrap_or_default(),
+        })
+    }
+}
+
 impl<
         T: PaymentMethodDataTypes
             + std::fmt::Debug
@@ -94,7 +506,11 @@ impl<
     ) -> Result<Self, Self::Error> {
         let adyen_card = AdyenCard {
             number: card.card_number.clone(),
-            card_holder_name,
+            expiry_month: card.card_exp_month.clone(),
+            expiry_year: card.card_exp_year.clone(),
+            cvc: Some(card.card_cvc.clone()),
+            holder_name: card_holder_name.or(card.card_holder_name.clone()),
+            brand: card.card_network.as_ref().map(CardBrand::from),
         };
         Ok(AdyenPaymentMethod::AdyenCard(Box::new(adyen_card)))
     }
@@ -137,13 +553,54 @@ impl<
         ),
     ) -> Result<Self, Self::Error> {
         let (item, card_data) = value;
+        let auth_type = AdyenAuthType::try_from(&item.router_data.connector_auth_type)?;
+        let request = &item.router_data.request;
         let payment_method = PaymentMethod::AdyenPaymentMethod(Box::new(
             AdyenPaymentMethod::try_from((card_data, None))?,
         ));
+        let return_url =
+            request
+                .return_url
+                .clone()
+                .ok_or(errors::ConnectorError::MissingRequiredField {
+                    field_name: "return_url",
+                })?;
+        let shopper_interaction = match request.off_session {
+            Some(true) => AdyenShopperInteraction::ContinuedAuthentication,
+            _ => AdyenShopperInteraction::Ecommerce,
+        };
+        let (recurring_processing_model, store_payment_method, shopper_reference) =
+            get_recurring_processing_model(request);
+        let browser_info = request
+            .browser_info
+            .as_ref()
+            .map(AdyenBrowserInfo::try_from)
+            .transpose()?;
+        let billing_address = request
+            .billing_address
+            .as_ref()
+            .map(AdyenAddress::try_from)
+            .transpose()?;
         Ok(AdyenPaymentRequest {
-            amount: 10,
-            merchant_account: "Testing".to_string(),
+            amount: Amount {
+                currency: item.router_data.request.currency,
+                value: convert_amount(
+                    item.connector.amount_converter,
+                    item.router_data.request.minor_amount,
+                    item.router_data.request.currency,
+                )?,
+            },
+            merchant_account: auth_type.merchant_account,
             payment_method,
+            reference: item.router_data.resource_common_data.payment_id.clone(),
+            return_url,
+            shopper_interaction,
+            recurring_processing_model,
+            shopper_reference,
+            store_payment_method,
+            browser_info,
+            billing_address,
+            additional_data: get_additional_data(request.capture_method),
         })
     }
 }
@@ -199,3 +656,482 @@ impl<
         }
     }
 }
+
+impl<
+        T: PaymentMethodDataTypes
+            + std::fmt::Debug
+            + std::marker::Sync
+            + std::marker::Send
+            + 'static
+            + Serialize,
+    >
+    TryFrom<
+        AdyenRouterData<
+            RouterDataV2<PSync, PaymentFlowData, PaymentsSyncData, PaymentsResponseData>,
+            T,
+        >,
+    > for AdyenRedirectRequest
+{
+    type Error = Error;
+    fn try_from(
+        item: AdyenRouterData<
+            RouterDataV2<PSync, PaymentFlowData, PaymentsSyncData, PaymentsResponseData>,
+            T,
+        >,
+    ) -> Result<Self, Self::Error> {
+        let redirect_result = item.router_data.request.encoded_data.clone().ok_or(
+            errors::ConnectorError::MissingRequiredField {
+                field_name: "encoded_data",
+            },
+        )?;
+        Ok(Self {
+            details: AdyenRedirectionDetails { redirect_result },
+        })
+    }
+}
+
+impl<
+        T: PaymentMethodDataTypes
+            + std::fmt::Debug
+            + std::marker::Sync
+            + std::marker::Send
+            + 'static
+            + Serialize,
+    >
+    TryFrom<
+        AdyenRouterData<
+            RouterDataV2<Capture, PaymentFlowData, PaymentsCaptureData, PaymentsResponseData>,
+            T,
+        >,
+    > for AdyenCaptureRequest
+{
+    type Error = Error;
+    fn try_from(
+        item: AdyenRouterData<
+            RouterDataV2<Capture, PaymentFlowData, PaymentsCaptureData, PaymentsResponseData>,
+            T,
+        >,
+    ) -> Result<Self, Self::Error> {
+        let auth_type = AdyenAuthType::try_from(&item.router_data.connector_auth_type)?;
+        Ok(Self {
+            merchant_account: auth_type.merchant_account,
+            amount: Amount {
+                currency: item.router_data.request.currency,
+                value: convert_amount(
+                    item.connector.amount_converter,
+                    item.router_data.request.minor_amount_to_capture,
+                    item.router_data.request.currency,
+                )?,
+            },
+            reference: item.router_data.resource_common_data.payment_id.clone(),
+        })
+    }
+}
+
+impl<
+        T: PaymentMethodDataTypes
+            + std::fmt::Debug
+            + std::marker::Sync
+            + std::marker::Send
+            + 'static
+            + Serialize,
+    >
+    TryFrom<
+        AdyenRouterData<
+            RouterDataV2<Void, PaymentFlowData, PaymentVoidData, PaymentsResponseData>,
+            T,
+        >,
+    > for AdyenCancelRequest
+{
+    type Error = Error;
+    fn try_from(
+        item: AdyenRouterData<
+            RouterDataV2<Void, PaymentFlowData, PaymentVoidData, PaymentsResponseData>,
+            T,
+        >,
+    ) -> Result<Self, Self::Error> {
+        let auth_type = AdyenAuthType::try_from(&item.router_data.connector_auth_type)?;
+        Ok(Self {
+            merchant_account: auth_type.merchant_account,
+            reference: item.router_data.resource_common_data.payment_id.clone(),
+        })
+    }
+}
+
+impl<
+        T: PaymentMethodDataTypes
+            + std::fmt::Debug
+            + std::marker::Sync
+            + std::marker::Send
+            + 'static
+            + Serialize,
+    >
+    TryFrom<
+        AdyenRouterData<RouterDataV2<Refund, RefundFlowData, RefundsData, RefundsResponseData>, T>,
+    > for AdyenRefundRequest
+{
+    type Error = Error;
+    fn try_from(
+        item: AdyenRouterData<
+            RouterDataV2<Refund, RefundFlowData, RefundsData, RefundsResponseData>,
+            T,
+        >,
+    ) -> Result<Self, Self::Error> {
+        let auth_type = AdyenAuthType::try_from(&item.router_data.connector_auth_type)?;
+        Ok(Self {
+            merchant_account: auth_type.merchant_account,
+            amount: Amount {
+                currency: item.router_data.request.currency,
+                value: convert_amount(
+                    item.connector.amount_converter,
+                    item.router_data.request.minor_refund_amount,
+                    item.router_data.request.currency,
+                )?,
+            },
+            merchant_refund_reason: item
+                .router_data
+                .request
+                .reason
+                .as_ref()
+                .map(|reason| AdyenRefundRequestReason::from_str(reason))
+                .transpose()?,
+            reference: item.router_data.request.refund_id.clone(),
+        })
+    }
+}
+
+impl<F, Req>
+    TryFrom<
+        ResponseRouterData<
+            AdyenPaymentResponse,
+            RouterDataV2<F, PaymentFlowData, Req, PaymentsResponseData>,
+        >,
+    > for RouterDataV2<F, PaymentFlowData, Req, PaymentsResponseData>
+{
+    type Error = Error;
+    fn try_from(
+        value: ResponseRouterData<
+            AdyenPaymentResponse,
+            RouterDataV2<F, PaymentFlowData, Req, PaymentsResponseData>,
+        >,
+    ) -> Result<Self, Self::Error> {
+        let ResponseRouterData {
+            response,
+            router_data,
+            http_code,
+        } = value;
+        let status = get_adyen_payment_status(&response.result_code);
+        let error = if response.refusal_reason.is_some()
+            || response.refusal_reason_code.is_some()
+            || status == AttemptStatus::Failure
+        {
+            let additional_data = response.additional_data.as_ref();
+            Some(ErrorResponse {
+                code: response
+                    .refusal_reason_code
+                    .clone()
+                    .unwrap_or_else(|| NO_ERROR_CODE.to_string()),
+                message: response
+                    .refusal_reason
+                    .clone()
+                    .unwrap_or_else(|| NO_ERROR_MESSAGE.to_string()),
+                reason: response.refusal_reason.clone(),
+                status_code: http_code,
+                attempt_status: Some(AttemptStatus::Failure),
+                connector_transaction_id: response.psp_reference.clone(),
+                network_decline_code: additional_data
+                    .and_then(|data| data.refusal_code_raw.clone()),
+                network_advice_code: additional_data
+                    .and_then(|data| data.merchant_advice_code.clone()),
+                network_error_message: additional_data
+                    .and_then(|data| data.refusal_reason_raw.clone()),
+            })
+        } else {
+            None
+        };
+        let redirection_data = response
+            .action
+            .and_then(AdyenRedirectAction::into_redirect_form)
+            .map(Box::new);
+        let payments_response_data = PaymentsResponseData::TransactionResponse {
+            resource_id: response
+                .psp_reference
+                .map_or(ResponseId::NoResponseId, ResponseId::ConnectorTransactionId),
+            redirection_data,
+            connector_response_reference_id: response.merchant_reference,
+            incremental_authorization_allowed: None,
+            status_code: http_code,
+        };
+        Ok(Self {
+            resource_common_data: PaymentFlowData {
+                status: error
+                    .as_ref()
+                    .and_then(|error| error.attempt_status)
+                    .unwrap_or(status),
+                ..router_data.resource_common_data
+            },
+            response: error.map_or_else(|| Ok(payments_response_data), Err),
+            ..router_data
+        })
+    }
+}
+
+impl
+    TryFrom<
+        ResponseRouterData<
+            AdyenCaptureResponse,
+            RouterDataV2<Capture, PaymentFlowData, PaymentsCaptureData, PaymentsResponseData>,
+        >,
+    > for RouterDataV2<Capture, PaymentFlowData, PaymentsCaptureData, PaymentsResponseData>
+{
+    type Error = Error;
+    fn try_from(
+        value: ResponseRouterData<
+            AdyenCaptureResponse,
+            RouterDataV2<Capture, PaymentFlowData, PaymentsCaptureData, PaymentsResponseData>,
+        >,
+    ) -> Result<Self, Self::Error> {
+        let ResponseRouterData {
+            response,
+            router_data,
+            http_code,
+        } = value;
+        // Adyen only acknowledges the modification here, the final outcome arrives through webhooks
+        Ok(Self {
+            resource_common_data: PaymentFlowData {
+                status: AttemptStatus::CaptureInitiated,
+                ..router_data.resource_common_data
+            },
+            response: Ok(PaymentsResponseData::TransactionResponse {
+                resource_id: ResponseId::ConnectorTransactionId(response.payment_psp_reference),
+                redirection_data: None,
+                connector_response_reference_id: Some(response.reference),
+                incremental_authorization_allowed: None,
+                status_code: http_code,
+            }),
+            ..router_data
+        })
+    }
+}
+
+impl
+    TryFrom<
+        ResponseRouterData<
+            AdyenCancelResponse,
+            RouterDataV2<Void, PaymentFlowData, PaymentVoidData, PaymentsResponseData>,
+        >,
+    > for RouterDataV2<Void, PaymentFlowData, PaymentVoidData, PaymentsResponseData>
+{
+    type Error = Error;
+    fn try_from(
+        value: ResponseRouterData<
+            AdyenCancelResponse,
+            RouterDataV2<Void, PaymentFlowData, PaymentVoidData, PaymentsResponseData>,
+        >,
+    ) -> Result<Self, Self::Error> {
+        let ResponseRouterData {
+            response,
+            router_data,
+            http_code,
+        } = value;
+        // Adyen only acknowledges the modification here, the final outcome arrives through webhooks
+        Ok(Self {
+            resource_common_data: PaymentFlowData {
+                status: AttemptStatus::VoidInitiated,
+                ..router_data.resource_common_data
+            },
+            response: Ok(PaymentsResponseData::TransactionResponse {
+                resource_id: ResponseId::ConnectorTransactionId(response.payment_psp_reference),
+                redirection_data: None,
+                connector_response_reference_id: Some(response.reference),
+                incremental_authorization_allowed: None,
+                status_code: http_code,
+            }),
+            ..router_data
+        })
+    }
+}
+
+impl
+    TryFrom<
+        ResponseRouterData<
+            AdyenRefundResponse,
+            RouterDataV2<Refund, RefundFlowData, RefundsData, RefundsResponseData>,
+        >,
+    > for RouterDataV2<Refund, RefundFlowData, RefundsData, RefundsResponseData>
+{
+    type Error = Error;
+    fn try_from(
+        value: ResponseRouterData<
+            AdyenRefundResponse,
+            RouterDataV2<Refund, RefundFlowData, RefundsData, RefundsResponseData>,
+        >,
+    ) -> Result<Self, Self::Error> {
+        let ResponseRouterData {
+            response,
+            router_data,
+            http_code,
+        } = value;
+        // Adyen only acknowledges the refund here, the final outcome arrives through webhooks
+        let refund_status = common_enums::RefundStatus::Pending;
+        Ok(Self {
+            resource_common_data: RefundFlowData {
+                status: refund_status,
+                ..router_data.resource_common_data
+            },
+            response: Ok(RefundsResponseData {
+                connector_refund_id: response.psp_reference,
+                refund_status,
+                status_code: http_code,
+            }),
+            ..router_data
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::connectors::adyen::Adyen;
+    use domain_types::cards::CardNumber;
+    use domain_types::connector_types::MandateIds;
+    use domain_types::payment_method::DefaultPCIHolder;
+    use domain_types::types::Connectors;
+
+    fn authorize_request_body(capture_method: Option<CaptureMethod>) -> serde_json::Value {
+        let card = Card {
+            card_number: RawCardNumber::<DefaultPCIHolder>(
+                CardNumber::from_str("4111111111111111").expect("test card number is valid"),
+            ),
+            card_exp_month: Secret::new("03".to_string()),
+            card_exp_year: Secret::new("2030".to_string()),
+            card_cvc: Secret::new("737".to_string()),
+            card_holder_name: None,
+            card_issuer: None,
+            card_network: None,
+            card_type: None,
+            card_issuing_country: None,
+            nick_name: None,
+            co_badged_card_data: None,
+        };
+        let router_data = RouterDataV2 {
+            flow: std::marker::PhantomData::<Authorize>,
+            resource_common_data: PaymentFlowData {
+                payment_id: "pay_123".to_string(),
+                attempt_id: "pay_123".to_string(),
+                status: AttemptStatus::Started,
+                amount_captured: None,
+                connectors: Connectors::default(),
+            },
+            connector_auth_type: ConnectorAuthType::BodyKey {
+                api_key: Secret::new("api_key".to_string()),
+                key1: Secret::new("TestMerchant".to_string()),
+            },
+            request: PaymentsAuthorizeData {
+                payment_method_data: PaymentMethodData::Card(card),
+                amount: 1000,
+                minor_amount: MinorUnit::new(1000),
+                currency: Currency::USD,
+                confirm: true,
+                mandate_id: MandateIds::default(),
+                return_url: Some("https://example.com/return".to_string()),
+                customer_id: None,
+                capture_method,
+                setup_future_usage: None,
+                off_session: None,
+                browser_info: None,
+                billing_address: None,
+            },
+            response: Err(ErrorResponse::default()),
+        };
+        let request = AdyenPaymentRequest::try_from(AdyenRouterData {
+            connector: Adyen::<DefaultPCIHolder>::new().clone(),
+            router_data,
+        })
+        .expect("authorize request should build");
+        serde_json::to_value(&request).expect("authorize request should serialize")
+    }
+
+    #[test]
+    fn holds_manual_captures_for_a_separate_capture() {
+        for capture_method in [CaptureMethod::Manual, CaptureMethod::ManualMultiple] {
+            let body = authorize_request_body(Some(capture_method));
+            assert_eq!(
+                body["additionalData"],
+                serde_json::json!({ "manualCapture": "true" }),
+                "{capture_method:?}"
+            );
+            assert!(
+                body.get("captureDelayHours").is_none(),
+                "{capture_method:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn leaves_other_captures_to_the_account_settings() {
+        for capture_method in [
+            None,
+            Some(CaptureMethod::Automatic),
+            Some(CaptureMethod::Scheduled),
+            Some(CaptureMethod::SequentialAutomatic),
+        ] {
+            let body = authorize_request_body(capture_method);
+            assert!(body["additionalData"].is_null(), "{capture_method:?}");
+            assert!(
+                body.get("captureDelayHours").is_none(),
+                "{capture_method:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn deserializes_three_ds2_action_without_url() {
+        let response: AdyenPaymentResponse = serde_json::from_str(
+            r#"{
+                "resultCode": "IdentifyShopper",
+                "pspReference": "QFQTPCQ8HXSKGK82",
+                "action": {
+                    "paymentData": "Ab02b4c0!BQABAgCW5sxB4e/==",
+                    "paymentMethodType": "scheme",
+                    "authorisationToken": "BQABAQB9sM12",
+                    "subtype": "fingerprint",
+                    "token": "eyJ0aHJlZURTTWV0aG9kTm90aWZpY2F0aW9uVVJMIjoi",
+                    "type": "threeDS2"
+                }
+            }"#,
+        )
+        .expect("threeDS2 action should deserialize");
+
+        assert!(matches!(response.result_code, AdyenStatus::IdentifyShopper));
+        let action = response.action.expect("action should be present");
+        assert_eq!(action.type_of_response, "threeDS2");
+        assert!(action.into_redirect_form().is_none());
+    }
+
+    #[test]
+    fn maps_redirect_action_to_form() {
+        let action: AdyenRedirectAction = serde_json::from_str(
+            r#"{
+                "paymentMethodType": "scheme",
+                "url": "https://checkoutshopper-test.adyen.com/checkoutshopper/threeDS/redirect",
+                "method": "GET",
+                "type": "redirect"
+            }"#,
+        )
+        .expect("redirect action should deserialize");
+
+        match action.into_redirect_form() {
+            Some(RedirectForm::Form {
+                endpoint, method, ..
+            }) => {
+                assert_eq!(
+                    endpoint,
+                    "https://checkoutshopper-test.adyen.com/checkoutshopper/threeDS/redirect"
+                );
+                assert_eq!(method, Method::Get);
+            }
+            other => panic!("expected a redirect form, got {other:?}"),
+        }
+    }
+}
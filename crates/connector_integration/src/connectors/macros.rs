@@ -1,6 +1,8 @@
 // use common_utils::errors::CustomResult;
-use common_utils::errors::CustomResult;
+use crate::types::ResponseRouterData;
+use common_utils::{errors::CustomResult, ext_traits::BytesExt};
 use domain_types::{errors, router_data_v2::RouterDataV2};
+use error_stack::ResultExt;
 use std::marker::PhantomData;
 pub trait FlowTypes {
     type Flow;
@@ -21,6 +23,15 @@ impl<F, FCD, Req, Resp> FlowTypes for &RouterDataV2<F, FCD, Req, Resp> {
     type Request = Req;
     type Response = Resp;
 }
+pub(crate) type RouterDataType<T> = RouterDataV2<
+    <T as FlowTypes>::Flow,
+    <T as FlowTypes>::FlowCommonData,
+    <T as FlowTypes>::Request,
+    <T as FlowTypes>::Response,
+>;
+
+pub(crate) type ResponseRouterDataType<T, R> = ResponseRouterData<R, RouterDataType<T>>;
+
 #[derive(Clone)]
 
 pub struct Bridge<Q, T, S>(pub PhantomData<(Q, T, S)>);
@@ -38,35 +49,35 @@ pub trait BridgeRequestResponse: Send + Sync {
     {
         Self::RequestBody::try_from(rd)
     }
-    // fn response(
-    //     &self,
-    //     bytes: bytes::Bytes,
-    // ) -> CustomResult<Self::ResponseBody, errors::ConnectorError>
-    // where
-    //     Self::ResponseBody: for<'a> serde::Deserialize<'a>,
-    // {
-    //     if bytes.is_empty() {
-    //         serde_json::from_str("{}")
-    //             .change_context(errors::ConnectorError::ResponseDeserializationFailed)
-    //     } else {
-    //         bytes
-    //             .parse_struct(std::any::type_name::<Self::ResponseBody>())
-    //             .change_context(errors::ConnectorError::ResponseDeserializationFailed)
-    //     }
-    // }
-
-    // fn router_data(
-    //     &self,
-    //     response: ResponseRouterDataType<Self::ConnectorInputData, Self::ResponseBody>,
-    // ) -> CustomResult<RouterDataType<Self::ConnectorInputData>, errors::ConnectorError>
-    // where
-    //     RouterDataType<Self::ConnectorInputData>: TryFrom<
-    //         ResponseRouterDataType<Self::ConnectorInputData, Self::ResponseBody>,
-    //         Error = error_stack::Report<errors::ConnectorError>,
-    //     >,
-    // {
-    //     RouterDataType::<Self::ConnectorInputData>::try_from(response)
-    // }
+    fn response(
+        &self,
+        bytes: bytes::Bytes,
+    ) -> CustomResult<Self::ResponseBody, errors::ConnectorError>
+    where
+        Self::ResponseBody: for<'a> serde::Deserialize<'a>,
+    {
+        if bytes.is_empty() {
+            serde_json::from_str("{}")
+                .change_context(errors::ConnectorError::ResponseDeserializationFailed)
+        } else {
+            bytes
+                .parse_struct(std::any::type_name::<Self::ResponseBody>())
+                .change_context(errors::ConnectorError::ResponseDeserializationFailed)
+        }
+    }
+
+    fn router_data(
+        &self,
+        response: ResponseRouterDataType<Self::ConnectorInputData, Self::ResponseBody>,
+    ) -> CustomResult<RouterDataType<Self::ConnectorInputData>, errors::ConnectorError>
+    where
+        RouterDataType<Self::ConnectorInputData>: TryFrom<
+            ResponseRouterDataType<Self::ConnectorInputData, Self::ResponseBody>,
+            Error = error_stack::Report<errors::ConnectorError>,
+        >,
+    {
+        RouterDataType::<Self::ConnectorInputData>::try_from(response)
+    }
 }
 
 macro_rules! expand_connector_input_data {
@@ -1,2 +1,3 @@
 pub mod connectors;
 pub mod types;
+pub(crate) mod utils;
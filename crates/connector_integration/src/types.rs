@@ -26,3 +26,14 @@ impl<T: PaymentMethodDataTypes + Debug + Default + Send + Sync + 'static + serde
         }
     }
 }
+
+pub struct ResponseRouterData<Response, RouterData> {
+    pub response: Response,
+    pub router_data: RouterData,
+    pub http_code: u16,
+}
+
+pub(crate) mod headers {
+    pub(crate) const CONTENT_TYPE: &str = "Content-Type";
+    pub(crate) const X_API_KEY: &str = "X-API-Key";
+}
@@ -0,0 +1,15 @@
+use common_enums::Currency;
+use common_utils::money::{AmountConvertor, MinorUnit};
+use domain_types::errors::ConnectorError;
+use error_stack::ResultExt;
+
+/// Renders an amount in the unit the connector expects
+pub(crate) fn convert_amount<T>(
+    amount_convertor: &dyn AmountConvertor<Output = T>,
+    amount: MinorUnit,
+    currency: Currency,
+) -> Result<T, error_stack::Report<ConnectorError>> {
+    amount_convertor
+        .convert(amount, currency)
+        .change_context(ConnectorError::AmountConversionFailed)
+}
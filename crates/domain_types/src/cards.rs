@@ -1,7 +1,8 @@
-use hyperswitch_masking::{Strategy, StrongSecret, WithType};
+use common_enums::CardNetwork;
+use hyperswitch_masking::{PeekInterface, Secret, Strategy, StrongSecret, WithType};
 
 use serde::{Deserialize, Serialize};
-use std::fmt;
+use std::{fmt, str::FromStr};
 
 pub enum CardNumberStrategy {}
 
@@ -12,7 +13,7 @@ where
     fn fmt(val: &T, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let val_str: &str = val.as_ref();
 
-        if val_str.len() < 15 || val_str.len() > 19 {
+        if val_str.len() < 12 || val_str.len() > 19 {
             return WithType::fmt(val, f);
         }
 
@@ -24,6 +25,308 @@ where
     }
 }
 
-/// Card number
-#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum CardNumberValidationError {
+    #[error("card number must only contain digits")]
+    InvalidCharacters,
+    #[error("card number must be between 12 and 19 digits long")]
+    InvalidLength,
+    #[error("card number failed the luhn check")]
+    LuhnCheckFailed,
+}
+
+/// Card number, only constructed from a number that passed validation
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "String")]
 pub struct CardNumber(StrongSecret<String, CardNumberStrategy>);
+
+impl CardNumber {
+    pub fn peek(&self) -> &str {
+        self.0.peek()
+    }
+
+    /// First six digits, identifying the issuer
+    pub fn get_card_isin(&self) -> String {
+        self.0.peek().chars().take(6).collect()
+    }
+
+    pub fn get_last4(&self) -> String {
+        let number = self.0.peek();
+        number
+            .chars()
+            .skip(number.len().saturating_sub(4))
+            .collect()
+    }
+
+    /// Detects the network from the issuer prefix of the card number.
+    ///
+    /// Cartes Bancaires is never detected: its cards are co-badged with Visa or Mastercard and
+    /// share their ranges, so they come back as the international network. Callers that want to
+    /// route through Cartes Bancaires have to send the network explicitly.
+    pub fn get_card_network(&self) -> Option<CardNetwork> {
+        let number = self.0.peek();
+        let in_range = |digits: usize, start: u32, end: u32| {
+            number
+                .get(..digits)
+                .and_then(|prefix| prefix.parse::<u32>().ok())
+                .is_some_and(|prefix| (start..=end).contains(&prefix))
+        };
+
+        if in_range(6, 508227, 508227)
+            || in_range(4, 5085, 5089)
+            || in_range(6, 603741, 603741)
+            || in_range(6, 606985, 607984)
+            || in_range(6, 608001, 608500)
+            || in_range(6, 652150, 653149)
+            || in_range(6, 817290, 817290)
+            || in_range(6, 817368, 817368)
+            || in_range(6, 817378, 817378)
+        {
+            Some(CardNetwork::RuPay)
+        } else if in_range(2, 34, 34) || in_range(2, 37, 37) {
+            Some(CardNetwork::AmericanExpress)
+        } else if in_range(4, 3528, 3589) {
+            Some(CardNetwork::JCB)
+        } else if in_range(3, 300, 305)
+            || in_range(4, 3095, 3095)
+            || in_range(2, 36, 36)
+            || in_range(2, 38, 39)
+        {
+            Some(CardNetwork::DinersClub)
+        } else if in_range(2, 51, 55) || in_range(4, 2221, 2720) {
+            Some(CardNetwork::Mastercard)
+        } else if in_range(4, 6011, 6011) || in_range(3, 644, 649) || in_range(2, 65, 65) {
+            Some(CardNetwork::Discover)
+        } else if in_range(2, 62, 62) || in_range(4, 8100, 8171) {
+            Some(CardNetwork::UnionPay)
+        } else if in_range(2, 50, 50)
+            || in_range(2, 56, 58)
+            || in_range(2, 63, 63)
+            || in_range(2, 67, 67)
+        {
+            Some(CardNetwork::Maestro)
+        } else if in_range(1, 4, 4) {
+            Some(CardNetwork::Visa)
+        } else {
+            None
+        }
+    }
+}
+
+fn is_luhn_valid(number: &str) -> bool {
+    let checksum: u32 = number
+        .chars()
+        .rev()
+        .filter_map(|digit| digit.to_digit(10))
+        .enumerate()
+        .map(|(position, digit)| match position % 2 {
+            0 => digit,
+            _ if digit * 2 > 9 => digit * 2 - 9,
+            _ => digit * 2,
+        })
+        .sum();
+    checksum % 10 == 0
+}
+
+impl FromStr for CardNumber {
+    type Err = CardNumberValidationError;
+
+    fn from_str(card_number: &str) -> Result<Self, Self::Err> {
+        let number: String = card_number.split_whitespace().collect();
+        if !number.chars().all(|digit| digit.is_ascii_digit()) {
+            return Err(CardNumberValidationError::InvalidCharacters);
+        }
+        if !(12..=19).contains(&number.len()) {
+            return Err(CardNumberValidationError::InvalidLength);
+        }
+        if !is_luhn_valid(&number) {
+            return Err(CardNumberValidationError::LuhnCheckFailed);
+        }
+        Ok(Self(StrongSecret::new(number)))
+    }
+}
+
+impl TryFrom<String> for CardNumber {
+    type Error = CardNumberValidationError;
+
+    fn try_from(card_number: String) -> Result<Self, Self::Error> {
+        Self::from_str(&card_number)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum CardExpiryValidationError {
+    #[error("expiry month must be between 1 and 12")]
+    InvalidMonth,
+    #[error("expiry year must have two or four digits")]
+    InvalidYear,
+    #[error("card has expired")]
+    Expired,
+}
+
+/// Card expiry, normalised to a two digit month and a four digit year
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CardExpiry {
+    pub month: Secret<String>,
+    pub year: Secret<String>,
+}
+
+impl CardExpiry {
+    /// Validates the expiry, a card stays valid until the end of its expiry month
+    pub fn new(month: &str, year: &str) -> Result<Self, CardExpiryValidationError> {
+        let month = month
+            .trim()
+            .parse::<u8>()
+            .ok()
+            .filter(|month| (1..=12).contains(month))
+            .ok_or(CardExpiryValidationError::InvalidMonth)?;
+        let year = year.trim();
+        let year = match (year.len(), year.parse::<i32>()) {
+            (2, Ok(year)) => 2000 + year,
+            (4, Ok(year)) => year,
+            _ => return Err(CardExpiryValidationError::InvalidYear),
+        };
+
+        let now = time::OffsetDateTime::now_utc();
+        if (year, month) < (now.year(), u8::from(now.month())) {
+            return Err(CardExpiryValidationError::Expired);
+        }
+        Ok(Self {
+            month: Secret::new(format!("{month:02}")),
+            year: Secret::new(year.to_string()),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn network_of(number: &str) -> Option<CardNetwork> {
+        CardNumber::from_str(number)
+            .expect("test card number should be valid")
+            .get_card_network()
+    }
+
+    #[test]
+    fn rejects_numbers_failing_the_luhn_check() {
+        assert!(CardNumber::from_str("4111111111111111").is_ok());
+        assert!(CardNumber::from_str("4111 1111 1111 1111").is_ok());
+        assert_eq!(
+            CardNumber::from_str("4111111111111112"),
+            Err(CardNumberValidationError::LuhnCheckFailed)
+        );
+        assert_eq!(
+            CardNumber::from_str("4111-1111-1111-1111"),
+            Err(CardNumberValidationError::InvalidCharacters)
+        );
+    }
+
+    #[test]
+    fn accepts_twelve_to_nineteen_digits() {
+        assert!(CardNumber::from_str("411111111117").is_ok());
+        assert!(CardNumber::from_str("4111111111111111110").is_ok());
+        assert_eq!(
+            CardNumber::from_str("41111111116"),
+            Err(CardNumberValidationError::InvalidLength)
+        );
+        assert_eq!(
+            CardNumber::from_str("41111111111111111115"),
+            Err(CardNumberValidationError::InvalidLength)
+        );
+    }
+
+    #[test]
+    fn detects_network_from_issuer_prefix() {
+        let cases = [
+            ("4111111111111111", Some(CardNetwork::Visa)),
+            ("5555555555554444", Some(CardNetwork::Mastercard)),
+            ("2223003122003222", Some(CardNetwork::Mastercard)),
+            ("378282246310005", Some(CardNetwork::AmericanExpress)),
+            ("3530111333300000", Some(CardNetwork::JCB)),
+            ("36227206271667", Some(CardNetwork::DinersClub)),
+            ("6011000000000004", Some(CardNetwork::Discover)),
+            ("6440000000000005", Some(CardNetwork::Discover)),
+            ("6500000000000002", Some(CardNetwork::Discover)),
+            ("620000000000000", Some(CardNetwork::UnionPay)),
+            ("675964982643844", Some(CardNetwork::Maestro)),
+            ("6069850000000003", Some(CardNetwork::RuPay)),
+            ("6080010000000009", Some(CardNetwork::RuPay)),
+            ("6521500000000006", Some(CardNetwork::RuPay)),
+            ("5085000000000007", Some(CardNetwork::RuPay)),
+            ("6037410000000005", Some(CardNetwork::RuPay)),
+            ("8172900000000006", Some(CardNetwork::RuPay)),
+            ("6000000000000007", None),
+        ];
+        for (number, expected) in cases {
+            assert_eq!(network_of(number), expected, "network of {number}");
+        }
+    }
+
+    #[test]
+    fn leaves_unassigned_eight_series_prefixes_undetected() {
+        let cases = [
+            ("8100000000000002", Some(CardNetwork::UnionPay)),
+            ("8171000000000006", Some(CardNetwork::UnionPay)),
+            ("8172000000000005", None),
+            ("8172890000000009", None),
+            ("8173680000000003", Some(CardNetwork::RuPay)),
+            ("8173780000000001", Some(CardNetwork::RuPay)),
+            ("8180000000000005", None),
+            ("8200000000000001", None),
+        ];
+        for (number, expected) in cases {
+            assert_eq!(network_of(number), expected, "network of {number}");
+        }
+    }
+
+    #[test]
+    fn parses_requested_network_case_insensitively() {
+        for (name, expected) in [
+            ("visa", CardNetwork::Visa),
+            ("MASTERCARD", CardNetwork::Mastercard),
+            ("AMEX", CardNetwork::AmericanExpress),
+            ("CartesBancaires", CardNetwork::CartesBancaires),
+            ("rupay", CardNetwork::RuPay),
+        ] {
+            assert_eq!(name.parse::<CardNetwork>(), Ok(expected));
+        }
+    }
+
+    #[test]
+    fn normalises_a_valid_expiry() {
+        let next_year = time::OffsetDateTime::now_utc().year() + 1;
+        let short_year = format!("{:02}", next_year % 100);
+        let expiry = CardExpiry::new("3", &short_year).expect("expiry should be valid");
+        assert_eq!(expiry.month.peek(), "03");
+        assert_eq!(expiry.year.peek(), &next_year.to_string());
+    }
+
+    #[test]
+    fn keeps_the_card_valid_until_the_end_of_its_expiry_month() {
+        let now = time::OffsetDateTime::now_utc();
+        let month = u8::from(now.month()).to_string();
+        assert!(CardExpiry::new(&month, &now.year().to_string()).is_ok());
+    }
+
+    #[test]
+    fn rejects_invalid_expiries() {
+        let next_year = (time::OffsetDateTime::now_utc().year() + 1).to_string();
+        assert_eq!(
+            CardExpiry::new("0", &next_year),
+            Err(CardExpiryValidationError::InvalidMonth)
+        );
+        assert_eq!(
+            CardExpiry::new("13", &next_year),
+            Err(CardExpiryValidationError::InvalidMonth)
+        );
+        assert_eq!(
+            CardExpiry::new("12", "203"),
+            Err(CardExpiryValidationError::InvalidYear)
+        );
+        assert_eq!(
+            CardExpiry::new("12", "2020"),
+            Err(CardExpiryValidationError::Expired)
+        );
+    }
+}
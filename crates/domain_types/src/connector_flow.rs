@@ -1,8 +1,28 @@
 #[derive(Debug, Clone)]
 pub struct Authorize;
 
+#[derive(Debug, Clone)]
+pub struct PSync;
+
+#[derive(Debug, Clone)]
+pub struct Capture;
+
+#[derive(Debug, Clone)]
+pub struct Void;
+
+#[derive(Debug, Clone)]
+pub struct Refund;
+
+#[derive(Debug, Clone)]
+pub struct RSync;
+
 #[derive(strum::Display)]
 #[strum(serialize_all = "snake_case")]
 pub enum FlowName {
     Authorize,
+    Psync,
+    Capture,
+    Void,
+    Refund,
+    Rsync,
 }
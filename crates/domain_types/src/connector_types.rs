@@ -1,11 +1,22 @@
 use crate::payment_method::{PaymentMethodData, PaymentMethodDataTypes};
 use crate::types::Connectors;
+use common_enums::{CaptureMethod, Currency, FutureUsage};
+use common_utils::money::MinorUnit;
+use hyperswitch_masking::Secret;
 use serde::{Deserialize, Serialize};
 use strum::{Display, EnumString};
+#[derive(Debug, Clone)]
+pub struct RefundFlowData {
+    pub status: common_enums::RefundStatus,
+    pub refund_id: Option<String>,
+    pub connectors: Connectors,
+}
+
 #[derive(Debug, Clone)]
 pub struct PaymentFlowData {
     pub payment_id: String,
     pub attempt_id: String,
+    pub status: common_enums::AttemptStatus,
     pub amount_captured: Option<i64>,
     pub connectors: Connectors,
 }
@@ -23,19 +34,128 @@ pub struct MandateIds {
 #[derive(Debug, Clone)]
 pub struct PaymentsAuthorizeData<T: PaymentMethodDataTypes> {
     pub payment_method_data: PaymentMethodData<T>,
+    pub amount: i64,
+    pub minor_amount: MinorUnit,
+    pub currency: Currency,
     pub confirm: bool,
     pub mandate_id: MandateIds,
+    /// Where the customer is sent back to after completing a redirection
+    pub return_url: Option<String>,
+    pub customer_id: Option<String>,
+    pub capture_method: Option<CaptureMethod>,
+    pub setup_future_usage: Option<FutureUsage>,
+    pub off_session: Option<bool>,
+    pub browser_info: Option<BrowserInformation>,
+    pub billing_address: Option<Address>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BrowserInformation {
+    pub color_depth: Option<u8>,
+    pub java_enabled: Option<bool>,
+    pub java_script_enabled: Option<bool>,
+    pub language: Option<String>,
+    pub screen_height: Option<u32>,
+    pub screen_width: Option<u32>,
+    /// Offset from UTC in minutes
+    pub time_zone: Option<i32>,
+    pub ip_address: Option<std::net::IpAddr>,
+    pub accept_header: Option<String>,
+    pub user_agent: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Address {
+    pub first_name: Option<Secret<String>>,
+    pub last_name: Option<Secret<String>>,
+    pub line1: Option<Secret<String>>,
+    pub line2: Option<Secret<String>>,
+    pub line3: Option<Secret<String>>,
+    pub city: Option<String>,
+    pub state: Option<Secret<String>>,
+    pub zip: Option<Secret<String>>,
+    /// ISO 3166-1 alpha-2 country code
+    pub country: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct PaymentsSyncData {
+    pub connector_transaction_id: String,
+    /// Opaque data returned by the connector on redirection, used to look up the outcome
+    pub encoded_data: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct PaymentsCaptureData {
+    pub amount_to_capture: i64,
+    pub minor_amount_to_capture: MinorUnit,
+    pub currency: Currency,
+    pub connector_transaction_id: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct PaymentVoidData {
+    pub connector_transaction_id: String,
+    pub cancellation_reason: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub enum ResponseId {
+    ConnectorTransactionId(String),
+    EncodedData(String),
+    #[default]
+    NoResponseId,
+}
+
+impl ResponseId {
+    pub fn get_connector_transaction_id(
+        &self,
+    ) -> error_stack::Result<String, crate::errors::ConnectorError> {
+        match self {
+            Self::ConnectorTransactionId(txn_id) => Ok(txn_id.to_string()),
+            _ => Err(error_stack::report!(
+                crate::errors::ConnectorError::MissingConnectorTransactionID
+            )),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum PaymentsResponseData {
     TransactionResponse {
+        resource_id: ResponseId,
+        redirection_data: Option<Box<crate::router_response_types::RedirectForm>>,
         connector_response_reference_id: Option<String>,
         incremental_authorization_allowed: Option<bool>,
         status_code: u16,
     },
 }
 
+#[derive(Debug, Clone)]
+pub struct RefundsData {
+    pub refund_id: String,
+    pub connector_transaction_id: String,
+    pub currency: Currency,
+    pub payment_amount: i64,
+    pub refund_amount: i64,
+    pub minor_refund_amount: MinorUnit,
+    pub reason: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct RefundSyncData {
+    pub connector_transaction_id: String,
+    pub connector_refund_id: String,
+    pub reason: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RefundsResponseData {
+    pub connector_refund_id: String,
+    pub refund_status: common_enums::RefundStatus,
+    pub status_code: u16,
+}
+
 #[derive(Clone, Copy, Debug, Display, EnumString)]
 #[strum(serialize_all = "snake_case")]
 pub enum ConnectorEnum {
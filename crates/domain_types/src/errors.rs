@@ -1,4 +1,6 @@
 /// Connector Errors
+use crate::router_data::ErrorResponse;
+use grpc::{errors::PaymentAuthorizationError, payments::PaymentStatus};
 use strum::Display;
 
 #[allow(missing_docs, missing_debug_implementations)]
@@ -19,6 +21,8 @@ pub enum ConnectorError {
     },
     #[error("Failed to deserialize connector response")]
     ResponseDeserializationFailed,
+    #[error("Failed to execute a processing step: {0:?}")]
+    ProcessingStepFailed(Option<bytes::Bytes>),
     #[error("Failed to parse custom routing rules from merchant account")]
     RoutingRulesParsingError,
     #[error("Failed to obtain preferred connector from merchant account")]
@@ -146,3 +150,110 @@ pub struct ApiError {
     pub error_message: String,
     pub error_object: Option<serde_json::Value>,
 }
+
+impl ApplicationErrorResponse {
+    pub fn get_api_error(&self) -> &ApiError {
+        match self {
+            Self::Unauthorized(api_error)
+            | Self::ForbiddenCommonResource(api_error)
+            | Self::ForbiddenPrivateResource(api_error)
+            | Self::Conflict(api_error)
+            | Self::Gone(api_error)
+            | Self::Unprocessable(api_error)
+            | Self::InternalServerError(api_error)
+            | Self::NotImplemented(api_error)
+            | Self::NotFound(api_error)
+            | Self::MethodNotAllowed(api_error)
+            | Self::BadRequest(api_error)
+            | Self::DomainError(api_error) => api_error,
+        }
+    }
+}
+
+/// Application errors reject the request before the connector is called, so the payment failed
+impl From<ApplicationErrorResponse> for PaymentAuthorizationError {
+    fn from(error: ApplicationErrorResponse) -> Self {
+        let api_error = error.get_api_error();
+        Self::new(
+            PaymentStatus::Failure.to_string(),
+            Some(api_error.error_message.clone()),
+            Some(api_error.sub_code.clone()),
+            Some(api_error.error_identifier.into()),
+        )
+    }
+}
+
+/// The payment status goes into the error object, so clients can tell a payment left pending,
+/// e.g. after a connector timeout, from one that failed
+impl From<PaymentAuthorizationError> for ApplicationErrorResponse {
+    fn from(error: PaymentAuthorizationError) -> Self {
+        let error_identifier = error
+            .status_code
+            .and_then(|status_code| u16::try_from(status_code).ok())
+            .unwrap_or(500);
+        let api_error = ApiError {
+            sub_code: error
+                .error_code
+                .unwrap_or_else(|| "INTERNAL_SERVER_ERROR".to_string()),
+            error_identifier,
+            error_object: Some(serde_json::json!({ "status": error.status })),
+            error_message: error.error_message.unwrap_or(error.status),
+        };
+        match error_identifier {
+            400 => Self::BadRequest(api_error),
+            401 => Self::Unauthorized(api_error),
+            403 => Self::ForbiddenCommonResource(api_error),
+            404 => Self::NotFound(api_error),
+            405 => Self::MethodNotAllowed(api_error),
+            409 => Self::Conflict(api_error),
+            410 => Self::Gone(api_error),
+            422 => Self::Unprocessable(api_error),
+            501 => Self::NotImplemented(api_error),
+            _ => Self::InternalServerError(api_error),
+        }
+    }
+}
+
+/// Errors returned by the connector are passed on as unprocessable, with the full
+/// connector error attached as the error object
+impl From<ErrorResponse> for ApplicationErrorResponse {
+    fn from(error: ErrorResponse) -> Self {
+        Self::Unprocessable(ApiError {
+            sub_code: error.code.clone(),
+            error_identifier: 422,
+            error_message: error.message.clone(),
+            error_object: serde_json::to_value(&error).ok(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reports_the_payment_status_in_the_error_object() {
+        let pending = ApplicationErrorResponse::from(PaymentAuthorizationError::new(
+            PaymentStatus::Pending.to_string(),
+            Some("Failed to obtain a response: Request timed out".to_string()),
+            Some("PAYMENT_AUTHORIZE_ERROR".to_string()),
+            None,
+        ));
+        let failed = ApplicationErrorResponse::from(PaymentAuthorizationError::new(
+            PaymentStatus::Failure.to_string(),
+            Some("Failed to obtain a response: Connection failed".to_string()),
+            Some("PAYMENT_AUTHORIZE_ERROR".to_string()),
+            None,
+        ));
+
+        assert_eq!(
+            pending.get_api_error().error_object,
+            Some(serde_json::json!({ "status": "pending" }))
+        );
+        assert_eq!(
+            failed.get_api_error().error_object,
+            Some(serde_json::json!({ "status": "failure" }))
+        );
+        assert_eq!(pending.get_api_error().error_identifier, 500);
+    }
+}
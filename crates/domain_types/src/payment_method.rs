@@ -1,39 +1,73 @@
+use crate::cards::CardNumber;
+use common_enums::CardNetwork;
+use hyperswitch_masking::Secret;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use std::fmt::Debug;
 pub trait PaymentMethodDataTypes: Clone {
-    type Inner: Default + Debug + Send + Eq + PartialEq + Serialize + DeserializeOwned + Clone;
-}
-
-#[derive(Default, Debug, Eq, PartialEq, Serialize, Deserialize, Clone)]
-pub struct DefaultCardData {
-    pub card_number: i64,
+    type Inner: Debug + Send + Eq + PartialEq + Serialize + DeserializeOwned + Clone;
 }
 
 #[derive(Default, Debug, Eq, PartialEq, Serialize, Deserialize, Clone)]
 pub struct DefaultPCIHolder;
 
 impl PaymentMethodDataTypes for DefaultPCIHolder {
-    type Inner = DefaultCardData;
+    type Inner = CardNumber;
 }
 
-#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Eq, PartialEq, Serialize, Deserialize)]
 pub struct RawCardNumber<T: PaymentMethodDataTypes>(pub T::Inner);
 
-impl DefaultCardData {
-    pub fn peek(&self) -> &i64 {
-        &self.card_number
+/// Never prints the number or token, whichever holder it comes from
+impl<T: PaymentMethodDataTypes> Debug for RawCardNumber<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("RawCardNumber(*** masked ***)")
     }
 }
 
 impl RawCardNumber<DefaultPCIHolder> {
-    pub fn peek(&self) -> &i64 {
+    pub fn peek(&self) -> &str {
         self.0.peek()
     }
 }
-#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+
+/// Holds a vault token reference in place of the card number, the vault proxy the connector
+/// call is routed through swaps it for the real number
+#[derive(Default, Debug, Eq, PartialEq, Serialize, Deserialize, Clone)]
+pub struct VaultTokenHolder;
+
+impl PaymentMethodDataTypes for VaultTokenHolder {
+    type Inner = String;
+}
+
+impl RawCardNumber<VaultTokenHolder> {
+    pub fn peek(&self) -> &str {
+        &self.0
+    }
+}
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 pub struct Card<T: PaymentMethodDataTypes> {
     pub card_number: RawCardNumber<T>,
+    pub card_exp_month: Secret<String>,
+    pub card_exp_year: Secret<String>,
+    pub card_cvc: Secret<String>,
+    pub card_holder_name: Option<Secret<String>>,
     pub card_issuer: Option<String>,
+    pub card_network: Option<CardNetwork>,
+    /// Credit, debit or prepaid
+    pub card_type: Option<String>,
+    /// ISO 3166-1 alpha-2 country code of the issuing bank
+    pub card_issuing_country: Option<String>,
+    pub nick_name: Option<Secret<String>>,
+    pub co_badged_card_data: Option<CoBadgedCardData>,
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct CoBadgedCardData {
+    pub co_badged_card_networks: Vec<CardNetwork>,
+    pub issuer_country_code: Option<String>,
+    /// Whether the issuer falls under interchange regulation, e.g. Durbin in the US
+    pub is_regulated: bool,
+    pub regulated_name: Option<String>,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
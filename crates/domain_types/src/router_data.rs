@@ -4,7 +4,7 @@ pub struct ErrorResponse {
     pub message: String,
     pub reason: Option<String>,
     pub status_code: u16,
-    // pub attempt_status: Option<common_enums::enums::AttemptStatus>,
+    pub attempt_status: Option<common_enums::AttemptStatus>,
     pub connector_transaction_id: Option<String>,
     pub network_decline_code: Option<String>,
     pub network_advice_code: Option<String>,
@@ -18,7 +18,23 @@ impl Default for ErrorResponse {
             message: "Something went wrong".to_string(),
             reason: None,
             status_code: http::StatusCode::INTERNAL_SERVER_ERROR.as_u16(),
-            // attempt_status: None,
+            attempt_status: None,
+            connector_transaction_id: None,
+            network_decline_code: None,
+            network_advice_code: None,
+            network_error_message: None,
+        }
+    }
+}
+
+impl ErrorResponse {
+    pub fn get_not_implemented() -> Self {
+        Self {
+            code: "IR_00".to_string(),
+            message: "This API is under development and will be made available soon.".to_string(),
+            reason: None,
+            status_code: http::StatusCode::INTERNAL_SERVER_ERROR.as_u16(),
+            attempt_status: None,
             connector_transaction_id: None,
             network_decline_code: None,
             network_advice_code: None,
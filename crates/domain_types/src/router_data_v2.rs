@@ -1,12 +1,136 @@
+use crate::errors::ConnectorError;
 use crate::router_data::ErrorResponse;
+use common_enums::Currency;
+use hyperswitch_masking::Secret;
+use std::collections::HashMap;
 use std::fmt::Debug;
 use std::marker::PhantomData;
 #[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
 #[serde(tag = "auth_type")]
 pub enum ConnectorAuthType {
     TemporaryAuth,
-    HeaderKey { api_key: String },
-    BodyKey { api_key: String, key1: String },
+    HeaderKey {
+        api_key: Secret<String>,
+    },
+    BodyKey {
+        api_key: Secret<String>,
+        key1: Secret<String>,
+    },
+    SignatureKey {
+        api_key: Secret<String>,
+        key1: Secret<String>,
+        api_secret: Secret<String>,
+    },
+    MultiAuthKey {
+        api_key: Secret<String>,
+        key1: Secret<String>,
+        api_secret: Secret<String>,
+        key2: Secret<String>,
+    },
+    CertificateAuth {
+        certificate: Secret<String>,
+        private_key: Secret<String>,
+    },
+    /// Credentials keyed by the currency they are used for
+    CurrencyAuthKey {
+        auth_key_map: HashMap<Currency, Secret<serde_json::Value>>,
+    },
+}
+
+impl ConnectorAuthType {
+    pub fn get_api_key(&self) -> error_stack::Result<&Secret<String>, ConnectorError> {
+        match self {
+            Self::HeaderKey { api_key }
+            | Self::BodyKey { api_key, .. }
+            | Self::SignatureKey { api_key, .. }
+            | Self::MultiAuthKey { api_key, .. } => Ok(api_key),
+            Self::TemporaryAuth | Self::CertificateAuth { .. } | Self::CurrencyAuthKey { .. } => {
+                Err(error_stack::report!(ConnectorError::FailedToObtainAuthType))
+            }
+        }
+    }
+
+    pub fn get_key1(&self) -> error_stack::Result<&Secret<String>, ConnectorError> {
+        match self {
+            Self::BodyKey { key1, .. }
+            | Self::SignatureKey { key1, .. }
+            | Self::MultiAuthKey { key1, .. } => Ok(key1),
+            Self::TemporaryAuth
+            | Self::HeaderKey { .. }
+            | Self::CertificateAuth { .. }
+            | Self::CurrencyAuthKey { .. } => {
+                Err(error_stack::report!(ConnectorError::FailedToObtainAuthType))
+            }
+        }
+    }
+
+    pub fn get_api_secret(&self) -> error_stack::Result<&Secret<String>, ConnectorError> {
+        match self {
+            Self::SignatureKey { api_secret, .. } | Self::MultiAuthKey { api_secret, .. } => {
+                Ok(api_secret)
+            }
+            Self::TemporaryAuth
+            | Self::HeaderKey { .. }
+            | Self::BodyKey { .. }
+            | Self::CertificateAuth { .. }
+            | Self::CurrencyAuthKey { .. } => {
+                Err(error_stack::report!(ConnectorError::FailedToObtainAuthType))
+            }
+        }
+    }
+
+    pub fn get_key2(&self) -> error_stack::Result<&Secret<String>, ConnectorError> {
+        match self {
+            Self::MultiAuthKey { key2, .. } => Ok(key2),
+            Self::TemporaryAuth
+            | Self::HeaderKey { .. }
+            | Self::BodyKey { .. }
+            | Self::SignatureKey { .. }
+            | Self::CertificateAuth { .. }
+            | Self::CurrencyAuthKey { .. } => {
+                Err(error_stack::report!(ConnectorError::FailedToObtainAuthType))
+            }
+        }
+    }
+
+    /// Returns the certificate and the private key, in that order
+    pub fn get_certificate_auth(
+        &self,
+    ) -> error_stack::Result<(&Secret<String>, &Secret<String>), ConnectorError> {
+        match self {
+            Self::CertificateAuth {
+                certificate,
+                private_key,
+            } => Ok((certificate, private_key)),
+            Self::TemporaryAuth
+            | Self::HeaderKey { .. }
+            | Self::BodyKey { .. }
+            | Self::SignatureKey { .. }
+            | Self::MultiAuthKey { .. }
+            | Self::CurrencyAuthKey { .. } => {
+                Err(error_stack::report!(ConnectorError::FailedToObtainAuthType))
+            }
+        }
+    }
+
+    pub fn get_currency_auth_key(
+        &self,
+        currency: Currency,
+    ) -> error_stack::Result<&Secret<serde_json::Value>, ConnectorError> {
+        match self {
+            Self::CurrencyAuthKey { auth_key_map } => auth_key_map
+                .get(&currency)
+                .ok_or_else(|| error_stack::report!(ConnectorError::FailedToObtainAuthType)),
+            Self::TemporaryAuth
+            | Self::HeaderKey { .. }
+            | Self::BodyKey { .. }
+            | Self::SignatureKey { .. }
+            | Self::MultiAuthKey { .. }
+            | Self::CertificateAuth { .. } => {
+                Err(error_stack::report!(ConnectorError::FailedToObtainAuthType))
+            }
+        }
+    }
 }
 
 // #[derive(Clone, Debug, serde::Serialize)]
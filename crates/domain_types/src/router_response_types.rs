@@ -6,4 +6,13 @@ pub struct Response {
     pub response: bytes::Bytes,
     /// status code
     pub status_code: u16,
-}
\ No newline at end of file
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+pub enum RedirectForm {
+    Form {
+        endpoint: String,
+        method: common_utils::request::Method,
+        form_fields: std::collections::HashMap<String, String>,
+    },
+}
@@ -1,14 +1,32 @@
+use crate::cards::{CardExpiry, CardExpiryValidationError, CardNumber};
 use crate::errors::{ApiError, ApplicationErrorResponse};
 // use crate::types::PaymentServiceAuthorizeRequest;
-use crate::connector_types::{MandateIds, PaymentFlowData, PaymentsAuthorizeData};
+use crate::connector_flow::{Authorize, Capture, PSync, Void};
+use crate::connector_types::{
+    Address, BrowserInformation, MandateIds, PaymentFlowData, PaymentVoidData,
+    PaymentsAuthorizeData, PaymentsCaptureData, PaymentsResponseData, PaymentsSyncData,
+    RefundFlowData, RefundSyncData, RefundsData, RefundsResponseData, ResponseId,
+};
 use crate::payment_method::{
-    Card, DefaultCardData, DefaultPCIHolder, PaymentMethodData, PaymentMethodDataTypes,
-    RawCardNumber,
+    Card, CoBadgedCardData, DefaultPCIHolder, PaymentMethodData, PaymentMethodDataTypes,
+    RawCardNumber, VaultTokenHolder,
 };
-use crate::utils::ForeignTryFrom;
+use crate::router_data_v2::RouterDataV2;
+use crate::router_response_types::RedirectForm;
+use crate::utils::{ForeignFrom, ForeignTryFrom};
+use common_enums::{AttemptStatus, CardNetwork, Currency};
+use common_utils::money::MinorUnit;
 use error_stack::ResultExt;
-use grpc::types::{CardDetails, PaymentMethod, PaymentServiceAuthorizeRequest};
+use grpc::payments::{PaymentStatus, RefundStatus};
+use grpc::types::{
+    CardDetails, PaymentMethod, PaymentServiceAuthorizeRequest, PaymentServiceAuthorizeResponse,
+    PaymentServiceCaptureRequest, PaymentServiceCaptureResponse, PaymentServiceGetRequest,
+    PaymentServiceGetResponse, PaymentServiceRefundRequest, PaymentServiceVoidRequest,
+    PaymentServiceVoidResponse, RefundServiceGetRequest, RefundServiceResponse,
+};
+use hyperswitch_masking::{ExposeInterface, PeekInterface};
 use serde::{Deserialize, Serialize};
+use std::str::FromStr;
 #[derive(Clone, Deserialize, Serialize, Debug, Default)]
 pub struct ConnectorParams {
     /// base url
@@ -20,6 +38,9 @@ pub struct ConnectorParams {
     pub secondary_base_url: Option<String>,
     #[serde(default)]
     pub third_base_url: Option<String>,
+    /// speak HTTP/2 without negotiating it first, only for connectors known to support it
+    #[serde(default)]
+    pub http2_prior_knowledge: bool,
 }
 
 impl ConnectorParams {
@@ -29,30 +50,608 @@ impl ConnectorParams {
             dispute_base_url: None,
             secondary_base_url: None,
             third_base_url: None,
+            http2_prior_knowledge: false,
         }
     }
 }
 
+#[derive(Clone, serde::Deserialize, serde::Serialize, Debug, Default)]
+pub struct Proxy {
+    /// proxy used for plain http connector calls
+    pub http_url: Option<String>,
+    /// proxy used for https connector calls
+    pub https_url: Option<String>,
+    /// hosts which are called without going through the proxy
+    #[serde(default)]
+    pub bypass_proxy_urls: Vec<String>,
+    /// seconds an idle pooled connection is kept open, reqwest's default when unset
+    pub idle_pool_connection_timeout: Option<u64>,
+    /// idle connections kept per connector host, unbounded when unset
+    pub max_idle_connections_per_host: Option<usize>,
+    /// root certificates trusted on top of the system trust store
+    #[serde(default)]
+    pub ca_certificates: Vec<CaCertificate>,
+    /// retries of failed connector calls, for the flows that opt into them
+    #[serde(default)]
+    pub retry: ConnectorRetry,
+}
+
+#[derive(Clone, serde::Deserialize, serde::Serialize, Debug)]
+#[serde(default)]
+pub struct ConnectorRetry {
+    /// attempts per connector call including the first one, `1` disables retries
+    pub max_attempts: u32,
+    /// delay before the first retry, doubled on each further one
+    pub base_delay_ms: u64,
+    /// upper bound of the delay between attempts
+    pub max_delay_ms: u64,
+}
+
+impl Default for ConnectorRetry {
+    fn default() -> Self {
+        Self {
+            max_attempts: 1,
+            base_delay_ms: 100,
+            max_delay_ms: 2000,
+        }
+    }
+}
+
+/// A PEM encoded root certificate, e.g. `{ path = "/etc/ssl/certs/proxy.pem" }`
+#[derive(Clone, serde::Deserialize, serde::Serialize, Debug, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum CaCertificate {
+    Path(String),
+    Pem(String),
+}
+
 #[derive(Clone, serde::Deserialize, serde::Serialize, Debug, Default)]
 pub struct Connectors {
     // Added pub
     pub adyen: ConnectorParams,
 }
 
+/// Parses an API string into its domain type, rejecting unknown values with a `BadRequest`
+fn parse_field<F: FromStr>(
+    value: &str,
+    sub_code: &str,
+    field_name: &str,
+) -> Result<F, error_stack::Report<ApplicationErrorResponse>> {
+    value.parse::<F>().map_err(|_| {
+        error_stack::report!(ApplicationErrorResponse::BadRequest(ApiError {
+            sub_code: sub_code.to_owned(),
+            error_identifier: 400,
+            error_message: format!("Invalid {field_name}: {value}"),
+            error_object: None,
+        }))
+    })
+}
+
+fn parse_currency(
+    currency: &str,
+) -> Result<Currency, error_stack::Report<ApplicationErrorResponse>> {
+    parse_field(currency, "INVALID_CURRENCY", "currency")
+}
+
+/// Rejects an empty API string with a `BadRequest`, e.g. an id the connector call is made with
+fn require_field(
+    value: &str,
+    sub_code: &str,
+    field_name: &str,
+) -> Result<(), error_stack::Report<ApplicationErrorResponse>> {
+    if value.is_empty() {
+        return Err(error_stack::report!(ApplicationErrorResponse::BadRequest(
+            ApiError {
+                sub_code: sub_code.to_owned(),
+                error_identifier: 400,
+                error_message: format!("Missing {field_name}"),
+                error_object: None,
+            }
+        )));
+    }
+    Ok(())
+}
+
+/// Flows acting on an existing payment can't reach the connector without its transaction id
+fn require_connector_transaction_id(
+    connector_transaction_id: &str,
+) -> Result<(), error_stack::Report<ApplicationErrorResponse>> {
+    require_field(
+        connector_transaction_id,
+        "MISSING_CONNECTOR_TRANSACTION_ID",
+        "connector transaction id",
+    )
+}
+
+impl ForeignTryFrom<grpc::types::BrowserInformation> for BrowserInformation {
+    type Error = ApplicationErrorResponse;
+    fn foreign_try_from(
+        value: grpc::types::BrowserInformation,
+    ) -> Result<Self, error_stack::Report<Self::Error>> {
+        Ok(Self {
+            color_depth: value.color_depth,
+            java_enabled: value.java_enabled,
+            java_script_enabled: value.java_script_enabled,
+            language: value.language,
+            screen_height: value.screen_height,
+            screen_width: value.screen_width,
+            time_zone: value.time_zone,
+            ip_address: value
+                .ip_address
+                .map(|ip_address| parse_field(&ip_address, "INVALID_IP_ADDRESS", "ip address"))
+                .transpose()?,
+            accept_header: value.accept_header,
+            user_agent: value.user_agent,
+        })
+    }
+}
+
+impl ForeignFrom<grpc::types::Address> for Address {
+    fn foreign_from(value: grpc::types::Address) -> Self {
+        Self {
+            first_name: value.first_name,
+            last_name: value.last_name,
+            line1: value.line1,
+            line2: value.line2,
+            line3: value.line3,
+            city: value.city,
+            state: value.state,
+            zip: value.zip,
+            country: value.country,
+        }
+    }
+}
+
+impl ForeignFrom<AttemptStatus> for PaymentStatus {
+    fn foreign_from(status: AttemptStatus) -> Self {
+        match status {
+            AttemptStatus::Started => Self::Started,
+            AttemptStatus::AuthenticationFailed => Self::AuthenticationFailed,
+            AttemptStatus::RouterDeclined => Self::RouterDeclined,
+            AttemptStatus::AuthenticationPending => Self::AuthenticationPending,
+            AttemptStatus::AuthenticationSuccessful => Self::AuthenticationSuccessful,
+            AttemptStatus::Authorized => Self::Authorized,
+            AttemptStatus::AuthorizationFailed => Self::AuthorizationFailed,
+            AttemptStatus::Charged => Self::Charged,
+            AttemptStatus::Authorizing => Self::Authorizing,
+            AttemptStatus::CodInitiated => Self::CodInitiated,
+            AttemptStatus::Voided => Self::Voided,
+            AttemptStatus::VoidInitiated => Self::VoidInitiated,
+            AttemptStatus::CaptureInitiated => Self::CaptureInitiated,
+            AttemptStatus::CaptureFailed => Self::CaptureFailed,
+            AttemptStatus::VoidFailed => Self::VoidFailed,
+            AttemptStatus::AutoRefunded => Self::AutoRefunded,
+            AttemptStatus::PartialCharged => Self::PartialCharged,
+            AttemptStatus::PartialChargedAndChargeable => Self::PartialChargedAndChargeable,
+            AttemptStatus::Unresolved => Self::Unresolved,
+            AttemptStatus::Pending => Self::Pending,
+            AttemptStatus::Failure => Self::Failure,
+            AttemptStatus::PaymentMethodAwaited => Self::PaymentMethodAwaited,
+            AttemptStatus::ConfirmationAwaited => Self::ConfirmationAwaited,
+            AttemptStatus::DeviceDataCollectionPending => Self::DeviceDataCollectionPending,
+        }
+    }
+}
+
+impl ForeignFrom<RedirectForm> for grpc::types::RedirectForm {
+    fn foreign_from(form: RedirectForm) -> Self {
+        match form {
+            RedirectForm::Form {
+                endpoint,
+                method,
+                form_fields,
+            } => Self::Form {
+                endpoint,
+                method: method.to_string(),
+                form_fields,
+            },
+        }
+    }
+}
+
+impl<T: PaymentMethodDataTypes>
+    ForeignFrom<
+        RouterDataV2<Authorize, PaymentFlowData, PaymentsAuthorizeData<T>, PaymentsResponseData>,
+    > for PaymentServiceAuthorizeResponse
+{
+    fn foreign_from(
+        router_data: RouterDataV2<
+            Authorize,
+            PaymentFlowData,
+            PaymentsAuthorizeData<T>,
+            PaymentsResponseData,
+        >,
+    ) -> Self {
+        match router_data.response {
+            Ok(PaymentsResponseData::TransactionResponse {
+                resource_id,
+                redirection_data,
+                connector_response_reference_id,
+                status_code,
+                ..
+            }) => Self {
+                status: PaymentStatus::foreign_from(router_data.resource_common_data.status),
+                connector_transaction_id: match resource_id {
+                    ResponseId::ConnectorTransactionId(id) => Some(id),
+                    ResponseId::EncodedData(_) | ResponseId::NoResponseId => None,
+                },
+                connector_response_reference_id,
+                redirection_data: redirection_data
+                    .map(|form| grpc::types::RedirectForm::foreign_from(*form)),
+                error_code: None,
+                error_message: None,
+                error_reason: None,
+                raw_connector_status_code: status_code,
+            },
+            // the flow status is only updated from successful connector responses, an error
+            // response without an attempt status means the authorization failed
+            Err(error) => Self {
+                status: PaymentStatus::foreign_from(
+                    error.attempt_status.unwrap_or(AttemptStatus::Failure),
+                ),
+                connector_transaction_id: error.connector_transaction_id,
+                connector_response_reference_id: None,
+                redirection_data: None,
+                error_code: Some(error.code),
+                error_message: Some(error.message),
+                error_reason: error.reason,
+                raw_connector_status_code: error.status_code,
+            },
+        }
+    }
+}
+
+impl ForeignFrom<RouterDataV2<PSync, PaymentFlowData, PaymentsSyncData, PaymentsResponseData>>
+    for PaymentServiceGetResponse
+{
+    fn foreign_from(
+        router_data: RouterDataV2<PSync, PaymentFlowData, PaymentsSyncData, PaymentsResponseData>,
+    ) -> Self {
+        let status = PaymentStatus::foreign_from(router_data.resource_common_data.status);
+        let requested_transaction_id = router_data.request.connector_transaction_id;
+        match router_data.response {
+            Ok(PaymentsResponseData::TransactionResponse {
+                resource_id,
+                connector_response_reference_id,
+                status_code,
+                ..
+            }) => Self {
+                status,
+                connector_transaction_id: match resource_id {
+                    ResponseId::ConnectorTransactionId(id) => Some(id),
+                    ResponseId::EncodedData(_) | ResponseId::NoResponseId => {
+                        Some(requested_transaction_id)
+                    }
+                },
+                connector_response_reference_id,
+                error_code: None,
+                error_message: None,
+                error_reason: None,
+                raw_connector_status_code: status_code,
+            },
+            // a failed lookup says nothing about the payment, it keeps the status it had unless
+            // the connector reports one
+            Err(error) => Self {
+                status: error
+                    .attempt_status
+                    .map_or(status, PaymentStatus::foreign_from),
+                connector_transaction_id: error
+                    .connector_transaction_id
+                    .or(Some(requested_transaction_id)),
+                connector_response_reference_id: None,
+                error_code: Some(error.code),
+                error_message: Some(error.message),
+                error_reason: error.reason,
+                raw_connector_status_code: error.status_code,
+            },
+        }
+    }
+}
+
+/// A capture the connector rejected failed, unless it says which status it left the payment in
+impl ForeignFrom<RouterDataV2<Capture, PaymentFlowData, PaymentsCaptureData, PaymentsResponseData>>
+    for PaymentServiceCaptureResponse
+{
+    fn foreign_from(
+        router_data: RouterDataV2<
+            Capture,
+            PaymentFlowData,
+            PaymentsCaptureData,
+            PaymentsResponseData,
+        >,
+    ) -> Self {
+        let requested_transaction_id = router_data.request.connector_transaction_id;
+        match router_data.response {
+            Ok(PaymentsResponseData::TransactionResponse {
+                resource_id,
+                connector_response_reference_id,
+                status_code,
+                ..
+            }) => Self {
+                status: PaymentStatus::foreign_from(router_data.resource_common_data.status),
+                connector_transaction_id: match resource_id {
+                    ResponseId::ConnectorTransactionId(id) => Some(id),
+                    ResponseId::EncodedData(_) | ResponseId::NoResponseId => {
+                        Some(requested_transaction_id)
+                    }
+                },
+                connector_response_reference_id,
+                error_code: None,
+                error_message: None,
+                error_reason: None,
+                raw_connector_status_code: status_code,
+            },
+            Err(error) => Self {
+                status: PaymentStatus::foreign_from(
+                    error.attempt_status.unwrap_or(AttemptStatus::CaptureFailed),
+                ),
+                connector_transaction_id: error
+                    .connector_transaction_id
+                    .or(Some(requested_transaction_id)),
+                connector_response_reference_id: None,
+                error_code: Some(error.code),
+                error_message: Some(error.message),
+                error_reason: error.reason,
+                raw_connector_status_code: error.status_code,
+            },
+        }
+    }
+}
+
+/// A void the connector rejected failed, unless it says which status it left the payment in
+impl ForeignFrom<RouterDataV2<Void, PaymentFlowData, PaymentVoidData, PaymentsResponseData>>
+    for PaymentServiceVoidResponse
+{
+    fn foreign_from(
+        router_data: RouterDataV2<Void, PaymentFlowData, PaymentVoidData, PaymentsResponseData>,
+    ) -> Self {
+        let requested_transaction_id = router_data.request.connector_transaction_id;
+        match router_data.response {
+            Ok(PaymentsResponseData::TransactionResponse {
+                resource_id,
+                connector_response_reference_id,
+                status_code,
+                ..
+            }) => Self {
+                status: PaymentStatus::foreign_from(router_data.resource_common_data.status),
+                connector_transaction_id: match resource_id {
+                    ResponseId::ConnectorTransactionId(id) => Some(id),
+                    ResponseId::EncodedData(_) | ResponseId::NoResponseId => {
+                        Some(requested_transaction_id)
+                    }
+                },
+                connector_response_reference_id,
+                error_code: None,
+                error_message: None,
+                error_reason: None,
+                raw_connector_status_code: status_code,
+            },
+            Err(error) => Self {
+                status: PaymentStatus::foreign_from(
+                    error.attempt_status.unwrap_or(AttemptStatus::VoidFailed),
+                ),
+                connector_transaction_id: error
+                    .connector_transaction_id
+                    .or(Some(requested_transaction_id)),
+                connector_response_reference_id: None,
+                error_code: Some(error.code),
+                error_message: Some(error.message),
+                error_reason: error.reason,
+                raw_connector_status_code: error.status_code,
+            },
+        }
+    }
+}
+
 impl ForeignTryFrom<(PaymentServiceAuthorizeRequest, Connectors, &String)> for PaymentFlowData {
     type Error = ApplicationErrorResponse;
     fn foreign_try_from(
         (value, connectors, metadata): (PaymentServiceAuthorizeRequest, Connectors, &String),
     ) -> Result<Self, error_stack::Report<Self::Error>> {
         Ok(PaymentFlowData {
-            payment_id: "1244".to_string(),
-            attempt_id: "1244".to_string(),
+            payment_id: value.request_ref_id.clone(),
+            attempt_id: value.request_ref_id,
+            status: AttemptStatus::Started,
+            amount_captured: None,
+            connectors,
+        })
+    }
+}
+
+impl ForeignTryFrom<(PaymentServiceGetRequest, Connectors)> for PaymentFlowData {
+    type Error = ApplicationErrorResponse;
+    fn foreign_try_from(
+        (value, connectors): (PaymentServiceGetRequest, Connectors),
+    ) -> Result<Self, error_stack::Report<Self::Error>> {
+        Ok(PaymentFlowData {
+            payment_id: value.request_ref_id.clone(),
+            attempt_id: value.request_ref_id,
+            status: AttemptStatus::Pending,
+            amount_captured: None,
+            connectors,
+        })
+    }
+}
+
+impl ForeignTryFrom<PaymentServiceGetRequest> for PaymentsSyncData {
+    type Error = ApplicationErrorResponse;
+
+    fn foreign_try_from(
+        value: PaymentServiceGetRequest,
+    ) -> Result<Self, error_stack::Report<Self::Error>> {
+        require_connector_transaction_id(&value.connector_transaction_id)?;
+        Ok(Self {
+            connector_transaction_id: value.connector_transaction_id,
+            encoded_data: value.encoded_data,
+        })
+    }
+}
+
+impl ForeignTryFrom<(PaymentServiceCaptureRequest, Connectors)> for PaymentFlowData {
+    type Error = ApplicationErrorResponse;
+    fn foreign_try_from(
+        (value, connectors): (PaymentServiceCaptureRequest, Connectors),
+    ) -> Result<Self, error_stack::Report<Self::Error>> {
+        Ok(PaymentFlowData {
+            payment_id: value.request_ref_id.clone(),
+            attempt_id: value.request_ref_id,
+            status: AttemptStatus::Pending,
+            amount_captured: None,
+            connectors,
+        })
+    }
+}
+
+impl ForeignTryFrom<PaymentServiceCaptureRequest> for PaymentsCaptureData {
+    type Error = ApplicationErrorResponse;
+
+    fn foreign_try_from(
+        value: PaymentServiceCaptureRequest,
+    ) -> Result<Self, error_stack::Report<Self::Error>> {
+        require_connector_transaction_id(&value.connector_transaction_id)?;
+        Ok(Self {
+            amount_to_capture: value.amount_to_capture,
+            minor_amount_to_capture: MinorUnit::new(value.minor_amount_to_capture),
+            currency: parse_currency(&value.currency)?,
+            connector_transaction_id: value.connector_transaction_id,
+        })
+    }
+}
+
+impl ForeignTryFrom<(PaymentServiceVoidRequest, Connectors)> for PaymentFlowData {
+    type Error = ApplicationErrorResponse;
+    fn foreign_try_from(
+        (value, connectors): (PaymentServiceVoidRequest, Connectors),
+    ) -> Result<Self, error_stack::Report<Self::Error>> {
+        Ok(PaymentFlowData {
+            payment_id: value.request_ref_id.clone(),
+            attempt_id: value.request_ref_id,
+            status: AttemptStatus::Pending,
             amount_captured: None,
             connectors,
         })
     }
 }
 
+impl ForeignTryFrom<PaymentServiceVoidRequest> for PaymentVoidData {
+    type Error = ApplicationErrorResponse;
+
+    fn foreign_try_from(
+        value: PaymentServiceVoidRequest,
+    ) -> Result<Self, error_stack::Report<Self::Error>> {
+        require_connector_transaction_id(&value.connector_transaction_id)?;
+        Ok(Self {
+            connector_transaction_id: value.connector_transaction_id,
+            cancellation_reason: value.cancellation_reason,
+        })
+    }
+}
+
+impl ForeignTryFrom<(PaymentServiceRefundRequest, Connectors)> for RefundFlowData {
+    type Error = ApplicationErrorResponse;
+    fn foreign_try_from(
+        (value, connectors): (PaymentServiceRefundRequest, Connectors),
+    ) -> Result<Self, error_stack::Report<Self::Error>> {
+        Ok(RefundFlowData {
+            status: common_enums::RefundStatus::Pending,
+            refund_id: Some(value.refund_id),
+            connectors,
+        })
+    }
+}
+
+impl ForeignTryFrom<PaymentServiceRefundRequest> for RefundsData {
+    type Error = ApplicationErrorResponse;
+
+    fn foreign_try_from(
+        value: PaymentServiceRefundRequest,
+    ) -> Result<Self, error_stack::Report<Self::Error>> {
+        require_connector_transaction_id(&value.connector_transaction_id)?;
+        Ok(Self {
+            refund_id: value.refund_id,
+            connector_transaction_id: value.connector_transaction_id,
+            currency: parse_currency(&value.currency)?,
+            payment_amount: value.payment_amount,
+            refund_amount: value.refund_amount,
+            minor_refund_amount: MinorUnit::new(value.minor_refund_amount),
+            reason: value.reason,
+        })
+    }
+}
+
+impl ForeignFrom<common_enums::RefundStatus> for RefundStatus {
+    fn foreign_from(status: common_enums::RefundStatus) -> Self {
+        match status {
+            common_enums::RefundStatus::Failure => Self::Failure,
+            common_enums::RefundStatus::ManualReview => Self::ManualReview,
+            common_enums::RefundStatus::Pending => Self::Pending,
+            common_enums::RefundStatus::Success => Self::Success,
+            common_enums::RefundStatus::TransactionFailure => Self::TransactionFailure,
+        }
+    }
+}
+
+/// Shared by refunds and refund syncs, a connector error is returned in the body with the
+/// refund status it left the refund in
+impl<F, Req> ForeignFrom<RouterDataV2<F, RefundFlowData, Req, RefundsResponseData>>
+    for RefundServiceResponse
+{
+    fn foreign_from(
+        router_data: RouterDataV2<F, RefundFlowData, Req, RefundsResponseData>,
+    ) -> Self {
+        match router_data.response {
+            Ok(response) => Self {
+                refund_status: RefundStatus::foreign_from(response.refund_status),
+                connector_refund_id: Some(response.connector_refund_id),
+                error_code: None,
+                error_message: None,
+                error_reason: None,
+                raw_connector_status_code: response.status_code,
+            },
+            Err(error) => Self {
+                refund_status: RefundStatus::foreign_from(router_data.resource_common_data.status),
+                connector_refund_id: None,
+                error_code: Some(error.code),
+                error_message: Some(error.message),
+                error_reason: error.reason,
+                raw_connector_status_code: error.status_code,
+            },
+        }
+    }
+}
+
+impl ForeignTryFrom<(RefundServiceGetRequest, Connectors)> for RefundFlowData {
+    type Error = ApplicationErrorResponse;
+    fn foreign_try_from(
+        (value, connectors): (RefundServiceGetRequest, Connectors),
+    ) -> Result<Self, error_stack::Report<Self::Error>> {
+        Ok(RefundFlowData {
+            status: common_enums::RefundStatus::Pending,
+            refund_id: Some(value.request_ref_id),
+            connectors,
+        })
+    }
+}
+
+impl ForeignTryFrom<RefundServiceGetRequest> for RefundSyncData {
+    type Error = ApplicationErrorResponse;
+
+    fn foreign_try_from(
+        value: RefundServiceGetRequest,
+    ) -> Result<Self, error_stack::Report<Self::Error>> {
+        require_field(
+            &value.connector_refund_id,
+            "MISSING_CONNECTOR_REFUND_ID",
+            "connector refund id",
+        )?;
+        Ok(Self {
+            connector_transaction_id: value.connector_transaction_id,
+            connector_refund_id: value.connector_refund_id,
+            reason: value.reason,
+        })
+    }
+}
+
 impl<
         T: PaymentMethodDataTypes
             + Default
@@ -72,20 +671,35 @@ impl<
     ) -> Result<Self, error_stack::Report<Self::Error>> {
         Ok(Self {
             // payment_method_data: todo!(),
+            // the card errors already carry the sub code telling the client what to fix
             payment_method_data: PaymentMethodData::<T>::foreign_try_from(
                 value.payment_method.clone(),
             )
-            .change_context(ApplicationErrorResponse::BadRequest(ApiError {
-                sub_code: "INVALID_PAYMENT_METHOD_DATA".to_owned(),
-                error_identifier: 400,
-                error_message: "Payment method data construction failed".to_owned(),
-                error_object: None,
-            }))?,
+            .attach_printable("Payment method data construction failed")?,
+            amount: value.amount,
+            minor_amount: MinorUnit::new(value.minor_amount),
+            currency: parse_currency(&value.currency)?,
             confirm: true,
             mandate_id: MandateIds {
                 mandate_id: None,
                 mandate_reference_id: None,
             },
+            return_url: value.return_url,
+            customer_id: value.customer_id,
+            capture_method: value
+                .capture_method
+                .map(|method| parse_field(&method, "INVALID_CAPTURE_METHOD", "capture method"))
+                .transpose()?,
+            setup_future_usage: value
+                .setup_future_usage
+                .map(|usage| parse_field(&usage, "INVALID_FUTURE_USAGE", "setup future usage"))
+                .transpose()?,
+            off_session: value.off_session,
+            browser_info: value
+                .browser_info
+                .map(BrowserInformation::foreign_try_from)
+                .transpose()?,
+            billing_address: value.billing_address.map(Address::foreign_from),
         })
     }
 }
@@ -99,46 +713,127 @@ impl CardConversionHelper<DefaultPCIHolder> for DefaultPCIHolder {
     fn convert_card_details(
         card: CardDetails,
     ) -> Result<Card<DefaultPCIHolder>, error_stack::Report<ApplicationErrorResponse>> {
-        Ok(Card {
-            card_number: RawCardNumber::<DefaultPCIHolder>(DefaultCardData {
-                card_number: card.card_number,
-            }),
-            // card_exp_month: card
-            //     .card_exp_month
-            //     .ok_or(ApplicationErrorResponse::BadRequest(ApiError {
-            //         sub_code: "MISSING_EXP_MONTH".to_owned(),
-            //         error_identifier: 400,
-            //         error_message: "Missing Card Expiry Month".to_owned(),
-            //         error_object: None,
-            //     }))?,
-            // card_exp_year: card
-            //     .card_exp_year
-            //     .ok_or(ApplicationErrorResponse::BadRequest(ApiError {
-            //         sub_code: "MISSING_EXP_YEAR".to_owned(),
-            //         error_identifier: 400,
-            //         error_message: "Missing Card Expiry Year".to_owned(),
-            //         error_object: None,
-            //     }))?,
-            // card_cvc: card
-            //     .card_cvc
-            //     .ok_or(ApplicationErrorResponse::BadRequest(ApiError {
-            //         sub_code: "MISSING_CVC".to_owned(),
-            //         error_identifier: 400,
-            //         error_message: "Missing CVC".to_owned(),
-            //         error_object: None,
-            //     }))?,
-            card_issuer: card.card_issuer,
-            // card_network,
-            // card_type: card.card_type,
-            // card_issuing_country: card.card_issuing_country_alpha2,
-            // bank_code: card.bank_code,
-            // nick_name: card.nick_name.map(|name| name.into()),
-            // card_holder_name: card.card_holder_name,
-            // co_badged_card_data: None,
-        })
+        let card_number = CardNumber::from_str(card.card_number.peek()).map_err(|err| {
+            error_stack::report!(ApplicationErrorResponse::BadRequest(ApiError {
+                sub_code: "INVALID_CARD_NUMBER".to_owned(),
+                error_identifier: 400,
+                error_message: format!("Invalid card number: {err}"),
+                error_object: None,
+            }))
+        })?;
+        let detected_network = card_number.get_card_network();
+        build_card(RawCardNumber(card_number), detected_network, card)
     }
 }
 
+impl CardConversionHelper<VaultTokenHolder> for VaultTokenHolder {
+    fn convert_card_details(
+        card: CardDetails,
+    ) -> Result<Card<VaultTokenHolder>, error_stack::Report<ApplicationErrorResponse>> {
+        if card.card_number.peek().trim().is_empty() {
+            return Err(ApplicationErrorResponse::BadRequest(ApiError {
+                sub_code: "MISSING_VAULT_TOKEN".to_owned(),
+                error_identifier: 400,
+                error_message: "Missing vault token in card number".to_owned(),
+                error_object: None,
+            }))?;
+        }
+        // the token carries no issuer prefix, so the network is only known if it is sent
+        build_card(RawCardNumber(card.card_number.clone().expose()), None, card)
+    }
+}
+
+/// Validates everything but the card number, which each holder stores in its own way
+fn build_card<T: PaymentMethodDataTypes>(
+    card_number: RawCardNumber<T>,
+    detected_network: Option<CardNetwork>,
+    card: CardDetails,
+) -> Result<Card<T>, error_stack::Report<ApplicationErrorResponse>> {
+    let card_network = card
+        .card_network
+        .map(|network| parse_field(&network, "INVALID_CARD_NETWORK", "card network"))
+        .transpose()?
+        .or(detected_network);
+    let card_exp_month = card
+        .card_exp_month
+        .ok_or(ApplicationErrorResponse::BadRequest(ApiError {
+            sub_code: "MISSING_EXP_MONTH".to_owned(),
+            error_identifier: 400,
+            error_message: "Missing Card Expiry Month".to_owned(),
+            error_object: None,
+        }))?;
+    let card_exp_year = card
+        .card_exp_year
+        .ok_or(ApplicationErrorResponse::BadRequest(ApiError {
+            sub_code: "MISSING_EXP_YEAR".to_owned(),
+            error_identifier: 400,
+            error_message: "Missing Card Expiry Year".to_owned(),
+            error_object: None,
+        }))?;
+    let card_expiry =
+        CardExpiry::new(card_exp_month.peek(), card_exp_year.peek()).map_err(|err| {
+            let sub_code = match err {
+                CardExpiryValidationError::InvalidMonth => "INVALID_EXP_MONTH",
+                CardExpiryValidationError::InvalidYear => "INVALID_EXP_YEAR",
+                CardExpiryValidationError::Expired => "CARD_EXPIRED",
+            };
+            error_stack::report!(ApplicationErrorResponse::BadRequest(ApiError {
+                sub_code: sub_code.to_owned(),
+                error_identifier: 400,
+                error_message: format!("Invalid card expiry: {err}"),
+                error_object: None,
+            }))
+        })?;
+    let card_cvc = card
+        .card_cvc
+        .ok_or(ApplicationErrorResponse::BadRequest(ApiError {
+            sub_code: "MISSING_CVC".to_owned(),
+            error_identifier: 400,
+            error_message: "Missing CVC".to_owned(),
+            error_object: None,
+        }))?;
+    if !(3..=4).contains(&card_cvc.peek().len())
+        || !card_cvc.peek().chars().all(|c| c.is_ascii_digit())
+    {
+        return Err(ApplicationErrorResponse::BadRequest(ApiError {
+            sub_code: "INVALID_CVC".to_owned(),
+            error_identifier: 400,
+            error_message: "CVC must be 3 or 4 digits".to_owned(),
+            error_object: None,
+        }))?;
+    }
+    let co_badged_card_data = card
+        .co_badged_card_data
+        .map(|data| {
+            Ok::<_, error_stack::Report<ApplicationErrorResponse>>(CoBadgedCardData {
+                co_badged_card_networks: data
+                    .co_badged_card_networks
+                    .iter()
+                    .map(|network| {
+                        parse_field(network, "INVALID_CARD_NETWORK", "co-badged card network")
+                    })
+                    .collect::<Result<_, _>>()?,
+                issuer_country_code: data.issuer_country_code,
+                is_regulated: data.is_regulated,
+                regulated_name: data.regulated_name,
+            })
+        })
+        .transpose()?;
+    Ok(Card {
+        card_number,
+        card_exp_month: card_expiry.month,
+        card_exp_year: card_expiry.year,
+        card_cvc,
+        card_holder_name: card.card_holder_name,
+        card_issuer: card.card_issuer,
+        card_network,
+        card_type: card.card_type,
+        card_issuing_country: card.card_issuing_country,
+        nick_name: card.nick_name,
+        co_badged_card_data,
+    })
+}
+
 impl<T> ForeignTryFrom<CardDetails> for Card<T>
 where
     T: PaymentMethodDataTypes
@@ -181,7 +876,7 @@ impl<
             // ============================================================================
             // CARD METHODS
             // ============================================================================
-            PaymentMethod::Card(card_details) => {
+            PaymentMethod::Card(card_details) | PaymentMethod::CardProxy(card_details) => {
                 let card = Card::<T>::foreign_try_from(card_details)?;
                 Ok(PaymentMethodData::Card(card))
             } // },
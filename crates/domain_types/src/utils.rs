@@ -2,6 +2,11 @@ use crate::errors;
 use error_stack::{report, Result, ResultExt};
 pub type Error = error_stack::Report<errors::ConnectorError>;
 
+pub trait ForeignFrom<F> {
+    /// Convert from a foreign type to the current type
+    fn foreign_from(from: F) -> Self;
+}
+
 pub trait ForeignTryFrom<F>: Sized {
     /// Custom error for conversion failure
     type Error;
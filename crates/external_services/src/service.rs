@@ -2,63 +2,155 @@ use common_enums::ApiClientError;
 use common_utils::{
     errors::CustomResult,
     ext_traits::AsyncExt,
-    request::{Method, Request, RequestContent},
+    request::{Headers, Method, Request, RequestContent},
 };
 use domain_types::{
     // connector_types::{ConnectorResponseHeaders, RawConnectorRequestResponse},
     errors::ConnectorError,
     router_data_v2::RouterDataV2,
-    // router_response_types::Response,
-    // types::Proxy,
     router_response_types::Response,
+    types::{CaCertificate, ConnectorRetry, Proxy},
 };
 use error_stack::{report, ResultExt};
-use hyperswitch_masking::{ErasedMaskSerialize, ExposeInterface, Maskable};
+use hyperswitch_masking::{ErasedMaskSerialize, ExposeInterface, Maskable, PeekInterface, Secret};
 use interfaces::connector_integration_v2::BoxedConnectorIntegrationV2;
+use rand::Rng;
 use reqwest::Client;
+use ring::digest;
 use serde_json::json;
-use std::{collections::HashMap, str::FromStr, sync::RwLock, time::Duration};
-// static DEFAULT_CLIENT: OnceCell<Client> = OnceCell::new();
-fn extract_raw_connector_request(connector_request: &Request) -> String {
-    // Extract actual body content
-    let body_content = match connector_request.body.as_ref() {
-        Some(request) => {
-            match request {
-                // For RawBytes (e.g., SOAP XML), use the string directly without JSON parsing
-                RequestContent::RawBytes(_) => {
-                    serde_json::Value::String(request.get_inner_value().expose())
-                }
-                // For other content types, try to parse as JSON
-                RequestContent::Json(_)
-                | RequestContent::FormUrlEncoded(_)
-                | RequestContent::FormData(_)
-                | RequestContent::Xml(_) => {
-                    let exposed_value = request.get_inner_value().expose();
-                    serde_json::from_str(&exposed_value).unwrap_or_else(|_| {
-                        // tracing::warn!("failed to parse body as JSON, treating as string in extract_raw_connector_request");
-                        serde_json::Value::String(exposed_value)
-                    })
-                }
-            }
+use std::{
+    collections::HashMap,
+    str::FromStr,
+    sync::{OnceLock, RwLock},
+    time::{Duration, Instant},
+};
+/// Clients are shared across requests so connections and TLS sessions are reused, one per
+/// distinct client configuration
+static CLIENT_CACHE: OnceLock<RwLock<HashMap<ClientCacheKey, CachedClient>>> = OnceLock::new();
+
+/// Clients kept at most, the oldest one is dropped to make room for a new configuration
+const MAX_CACHED_CLIENTS: usize = 64;
+
+struct CachedClient {
+    client: Client,
+    created_at: Instant,
+}
+
+#[derive(Clone, PartialEq, Eq, Hash)]
+struct ClientCacheKey {
+    http_proxy: Option<String>,
+    https_proxy: Option<String>,
+    idle_pool_connection_timeout: Option<u64>,
+    max_idle_connections_per_host: Option<usize>,
+    http2_prior_knowledge: bool,
+    ca_certificates: Vec<CaCertificate>,
+    /// SHA-256 of the request's CA certificate, client certificate and key, so the key
+    /// material itself isn't kept around for the lifetime of the client
+    identity_fingerprint: Option<Vec<u8>>,
+}
+
+impl ClientCacheKey {
+    fn new(proxy_config: &Proxy, should_bypass_proxy: bool, request: &Request) -> Self {
+        let (http_proxy, https_proxy) = if should_bypass_proxy {
+            (None, None)
+        } else {
+            (
+                proxy_config.http_url.clone(),
+                proxy_config.https_url.clone(),
+            )
+        };
+        Self {
+            http_proxy,
+            https_proxy,
+            idle_pool_connection_timeout: proxy_config.idle_pool_connection_timeout,
+            max_idle_connections_per_host: proxy_config.max_idle_connections_per_host,
+            http2_prior_knowledge: request.http2_prior_knowledge,
+            ca_certificates: proxy_config.ca_certificates.clone(),
+            identity_fingerprint: identity_fingerprint(request),
+        }
+    }
+}
+
+fn identity_fingerprint(request: &Request) -> Option<Vec<u8>> {
+    let pems = [
+        request.ca_certificate.as_ref(),
+        request.certificate.as_ref(),
+        request.certificate_key.as_ref(),
+    ];
+    if pems.iter().all(Option::is_none) {
+        return None;
+    }
+    let mut context = digest::Context::new(&digest::SHA256);
+    for pem in pems {
+        // length prefixed, so a PEM can't be shifted from one part into the next
+        let pem = pem.map(|pem| pem.peek().as_bytes()).unwrap_or_default();
+        context.update(&u64::try_from(pem.len()).unwrap_or(u64::MAX).to_be_bytes());
+        context.update(pem);
+    }
+    Some(context.finish().as_ref().to_vec())
+}
+
+/// How much of a connector request is kept when it is captured for logs
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum RequestCapture {
+    /// Secrets in the body and masked headers are replaced, safe for logs
+    #[default]
+    Masked,
+    /// The request exactly as sent, including card data and API keys
+    Unmasked,
+}
+
+const MASKED_VALUE: &str = "*** masked ***";
+
+pub fn extract_raw_connector_request(
+    connector_request: &Request,
+    capture: RequestCapture,
+) -> String {
+    let body_content = match (connector_request.body.as_ref(), capture) {
+        (None, _) => serde_json::Value::Null,
+        (Some(RequestContent::FormData(_)), _) => serde_json::Value::Null,
+        // Secret fields serialize to their masked form
+        (
+            Some(
+                RequestContent::Json(body)
+                | RequestContent::FormUrlEncoded(body)
+                | RequestContent::Xml(body),
+            ),
+            RequestCapture::Masked,
+        ) => body
+            .masked_serialize()
+            .unwrap_or_else(|_| serde_json::Value::String(MASKED_VALUE.to_string())),
+        // Raw bytes carry no type information to mask by
+        (Some(RequestContent::RawBytes(_)), RequestCapture::Masked) => {
+            serde_json::Value::String(MASKED_VALUE.to_string())
+        }
+        (Some(request @ RequestContent::RawBytes(_)), RequestCapture::Unmasked) => {
+            serde_json::Value::String(request.get_inner_value().expose())
+        }
+        (Some(request), RequestCapture::Unmasked) => {
+            let exposed_value = request.get_inner_value().expose();
+            serde_json::from_str(&exposed_value)
+                .unwrap_or_else(|_| serde_json::Value::String(exposed_value))
         }
-        None => serde_json::Value::Null,
     };
-    // Extract unmasked headers
     let headers_content = connector_request
         .headers
         .iter()
         .map(|(k, v)| {
-            let value = match v {
-                Maskable::Normal(val) => val.clone(),
-                Maskable::Masked(val) => val.clone().expose().to_string(),
+            let value = match (v, capture) {
+                (Maskable::Normal(val), _) => val.clone(),
+                (Maskable::Masked(_), RequestCapture::Masked) => MASKED_VALUE.to_string(),
+                (Maskable::Masked(val), RequestCapture::Unmasked) => {
+                    val.clone().expose().to_string()
+                }
             };
             (k.clone(), value)
         })
         .collect::<HashMap<_, _>>();
 
-    // Create complete request with actual content
     json!({
         "url": connector_request.url,
+        "query": connector_request.query,
         "method": connector_request.method.to_string(),
         "headers": headers_content,
         "body": body_content
@@ -66,42 +158,104 @@ fn extract_raw_connector_request(connector_request: &Request) -> String {
     .to_string()
 }
 
-fn load_custom_ca_certificate_from_content(
-    mut client_builder: reqwest::ClientBuilder,
-    // cert_content: &str,
+fn load_ca_certificate(
+    client_builder: reqwest::ClientBuilder,
+    pem: &[u8],
 ) -> CustomResult<reqwest::ClientBuilder, ApiClientError> {
-    let cert = "-----BEGIN CERTIFICATE-----\nMIIDNTCCAh2gAwIBAgIULZTaHx0R3u0ua/wOA+7rHxSsABkwDQYJKoZIhvcNAQEL\nBQAwKDESMBAGA1UEAwwJbWl0bXByb3h5MRIwEAYDVQQKDAltaXRtcHJveHkwHhcN\nMjUxMDI4MDgxNDAyWhcNMzUxMDI4MDgxNDAyWjAoMRIwEAYDVQQDDAltaXRtcHJv\neHkxEjAQBgNVBAoMCW1pdG1wcm94eTCCASIwDQYJKoZIhvcNAQEBBQADggEPADCC\nAQoCggEBANUNbRjA52/3aABvTEOWMiNRQZvrmHKul1ZwNeQ7mKIwMZstmahRc6xo\nTqv80yOurm+NGSc845zPCA2C6NGPJ182TF/JyjZ3svF+h5eA/IJ7EHSyeIE9k7k6\ntZ+q4TmWGrEY7O4AcZnynI2AzUeaVlehyM/P02hOx1pxPu0OCMDYAxRY86VnNII4\ncHYWhqQnxtBlsJxNwaLvYpiL+IOzolqWzatVMUjtSj54kMcB23MS2+GjwKJav8qm\nfzJe0uLY8FzcwpIdaIJ1TJ0inawxBA8FQlNrQNmwoh0Sk2HKLgGYduqJB23tMwdu\nZfz8rLPYDXcrM6DyCL3pmjUPJuyx3FECAwEAAaNXMFUwDwYDVR0TAQH/BAUwAwEB\n/zATBgNVHSUEDDAKBggrBgEFBQcDATAOBgNVHQ8BAf8EBAMCAQYwHQYDVR0OBBYE\nFBP4TdfnOBtlz/lr+A0pKSn1UChfMA0GCSqGSIb3DQEBCwUAA4IBAQBeZhahkWT5\n7HyLteOuY3CR9ZbYx+diEGwBwIeIH0ALt0b1DH+t5iLRARv1vLVv2EGJLuAFDDPy\n9/FgO9NOlJ9b0p/wo9aDx7rO9Vc+Xtr5Damr5+Re98mjzalIF7C34WAgE/0xmNxM\nXcVvhNcarkoJWxQ4asGmAq93KMiIt8F2/cHX4rikBXm3en0hWxy6oBi3lDzNbcZ/\n24Z9eh6szcyBCf6fHQiCtEHZij64A0PxHYKEwB+TFfvzkupvmA76C2MYyWzRkkn+\nRHsX+RzJx6qYXvUhdo93X9ciiCFI1Jdd5sKoLCMwz7Rnnr70dxFLSOZuZDQp4chQ\nQsn/SJD7GXtG\n-----END CERTIFICATE-----";
-    let certificate = reqwest::Certificate::from_pem(cert.as_bytes())
-        .change_context(ApiClientError::InvalidProxyConfiguration)
-        .attach_printable("Failed to parse certificate PEM from provided content")?;
-    client_builder = client_builder.add_root_certificate(certificate);
-    Ok(client_builder)
+    let certificate = reqwest::Certificate::from_pem(pem)
+        .change_context(ApiClientError::CertificateDecodeFailed)
+        .attach_printable("Failed to parse CA certificate PEM")?;
+    Ok(client_builder.add_root_certificate(certificate))
+}
+
+fn load_client_identity(
+    client_builder: reqwest::ClientBuilder,
+    certificate: &Secret<String>,
+    certificate_key: &Secret<String>,
+) -> CustomResult<reqwest::ClientBuilder, ApiClientError> {
+    let pem = format!("{}\n{}", certificate_key.peek(), certificate.peek());
+    let identity = reqwest::Identity::from_pem(pem.as_bytes())
+        .change_context(ApiClientError::CertificateDecodeFailed)
+        .attach_printable("Failed to parse client certificate and key PEM")?;
+    // `Identity::from_pem` is only understood by the rustls backend
+    Ok(client_builder.use_rustls_tls().identity(identity))
 }
 
 fn get_client_builder(
-    proxy_url: Option<&str>,
+    proxy_config: &Proxy,
+    should_bypass_proxy: bool,
+    request: &Request,
 ) -> CustomResult<reqwest::ClientBuilder, ApiClientError> {
-    // let url = "http://localhost:8081".to_string();
     let mut client_builder = Client::builder().redirect(reqwest::redirect::Policy::none());
 
-    if let Some(proxy_url) = proxy_url {
-        // let t = proxy_url.to_string();
-        client_builder = client_builder.proxy(
-            reqwest::Proxy::https(proxy_url.to_string())
-                .change_context(ApiClientError::InvalidProxyConfiguration)
-                .inspect_err(|err| {
-                    println!("{:?}", err);
-                })?,
-        );
+    if let Some(timeout) = proxy_config.idle_pool_connection_timeout {
+        client_builder = client_builder.pool_idle_timeout(Duration::from_secs(timeout));
+    }
+    if let Some(max_idle) = proxy_config.max_idle_connections_per_host {
+        client_builder = client_builder.pool_max_idle_per_host(max_idle);
+    }
+    // HTTP/2 is negotiated through ALPN unless the connector opted into prior knowledge
+    if request.http2_prior_knowledge {
+        client_builder = client_builder.http2_prior_knowledge();
+    }
+
+    if !should_bypass_proxy {
+        // Proxy all HTTPS traffic through the configured HTTPS proxy
+        if let Some(url) = proxy_config.https_url.as_ref() {
+            client_builder = client_builder.proxy(
+                reqwest::Proxy::https(url)
+                    .change_context(ApiClientError::InvalidProxyConfiguration)
+                    .attach_printable("HTTPS proxy configuration error")?,
+            );
+        }
+
+        // Proxy all HTTP traffic through the configured HTTP proxy
+        if let Some(url) = proxy_config.http_url.as_ref() {
+            client_builder = client_builder.proxy(
+                reqwest::Proxy::http(url)
+                    .change_context(ApiClientError::InvalidProxyConfiguration)
+                    .attach_printable("HTTP proxy configuration error")?,
+            );
+        }
+    }
+
+    // The system trust store stays in use, configured certificates are trusted in addition
+    for ca_certificate in &proxy_config.ca_certificates {
+        client_builder = match ca_certificate {
+            CaCertificate::Path(path) => {
+                let pem = std::fs::read(path)
+                    .change_context(ApiClientError::CertificateDecodeFailed)
+                    .attach_printable_lazy(|| format!("Failed to read CA certificate {path}"))?;
+                load_ca_certificate(client_builder, &pem)?
+            }
+            CaCertificate::Pem(pem) => load_ca_certificate(client_builder, pem.as_bytes())?,
+        };
+    }
+    if let Some(pem) = request.ca_certificate.as_ref() {
+        client_builder = load_ca_certificate(client_builder, pem.peek().as_bytes())?;
+    }
+
+    match (
+        request.certificate.as_ref(),
+        request.certificate_key.as_ref(),
+    ) {
+        (Some(certificate), Some(certificate_key)) => {
+            client_builder = load_client_identity(client_builder, certificate, certificate_key)?;
+        }
+        (None, None) => {}
+        _ => Err(report!(ApiClientError::CertificateDecodeFailed))
+            .attach_printable("Client certificate and key must be provided together")?,
     }
-    client_builder = load_custom_ca_certificate_from_content(client_builder)?;
     Ok(client_builder)
 }
 
-fn get_base_client(proxy_url: Option<&str>) -> CustomResult<Client, ApiClientError> {
-    // Use DEFAULT_CLIENT for non-proxy scenarios
+fn get_base_client(
+    proxy_config: &Proxy,
+    should_bypass_proxy: bool,
+    request: &Request,
+) -> CustomResult<Client, ApiClientError> {
     let client = {
-        get_client_builder(proxy_url)?
+        get_client_builder(proxy_config, should_bypass_proxy, request)?
             .build()
             .change_context(ApiClientError::ClientConstructionFailed)
             .attach_printable("Failed to construct default client")?
@@ -109,18 +263,88 @@ fn get_base_client(proxy_url: Option<&str>) -> CustomResult<Client, ApiClientErr
     Ok(client)
 }
 
-pub fn create_client(proxy_url: Option<&str>) -> CustomResult<Client, ApiClientError> {
-    get_base_client(proxy_url)
+/// Besides the proxy configuration, the client trusts `Request::ca_certificate` and presents
+/// `Request::certificate`/`certificate_key` for mutual TLS, so each identity gets its own client
+pub fn create_client(
+    proxy_config: &Proxy,
+    should_bypass_proxy: bool,
+    request: &Request,
+) -> CustomResult<Client, ApiClientError> {
+    let key = ClientCacheKey::new(proxy_config, should_bypass_proxy, request);
+    let cache = CLIENT_CACHE.get_or_init(Default::default);
+    if let Some(client) = cache
+        .read()
+        .ok()
+        .and_then(|clients| clients.get(&key).map(|cached| cached.client.clone()))
+    {
+        return Ok(client);
+    }
+
+    let client = get_base_client(proxy_config, should_bypass_proxy, request)?;
+    let Ok(mut clients) = cache.write() else {
+        return Ok(client);
+    };
+    Ok(cache_client(&mut clients, key, client))
+}
+
+/// Keeps `client` for `key` and returns the client to use for it
+fn cache_client(
+    clients: &mut HashMap<ClientCacheKey, CachedClient>,
+    key: ClientCacheKey,
+    client: Client,
+) -> Client {
+    // a concurrent request may have built the same client meanwhile, keep whichever came first
+    if let Some(cached) = clients.get(&key) {
+        return cached.client.clone();
+    }
+    if clients.len() >= MAX_CACHED_CLIENTS {
+        let oldest = clients
+            .iter()
+            .min_by_key(|(_, cached)| cached.created_at)
+            .map(|(key, _)| key.clone());
+        if let Some(oldest) = oldest {
+            clients.remove(&oldest);
+        }
+    }
+    clients.insert(
+        key,
+        CachedClient {
+            client: client.clone(),
+            created_at: Instant::now(),
+        },
+    );
+    client
+}
+
+trait HeaderExt {
+    fn construct_header_map(self) -> CustomResult<reqwest::header::HeaderMap, ApiClientError>;
+}
+
+impl HeaderExt for Headers {
+    fn construct_header_map(self) -> CustomResult<reqwest::header::HeaderMap, ApiClientError> {
+        use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+
+        self.into_iter().try_fold(
+            HeaderMap::new(),
+            |mut header_map, (header_name, header_value)| {
+                let header_name = HeaderName::from_str(&header_name)
+                    .change_context(ApiClientError::HeaderMapConstructionFailed)?;
+                let header_value = header_value.into_inner();
+                let header_value = HeaderValue::from_str(&header_value)
+                    .change_context(ApiClientError::HeaderMapConstructionFailed)?;
+                header_map.append(header_name, header_value);
+                Ok(header_map)
+            },
+        )
+    }
 }
 
 async fn handle_response(
     response: CustomResult<reqwest::Response, ApiClientError>,
 ) -> CustomResult<Result<Response, Response>, ApiClientError> {
-    println!("{:?} RESPONSE", response);
     response
         .async_map(|resp| async {
             let status_code = resp.status().as_u16();
-            println!("{:?} STATUS CODE", status_code);
             let headers = Some(resp.headers().to_owned());
             match status_code {
                 200..=202 | 302 | 204 => {
@@ -169,50 +393,62 @@ async fn handle_response(
         .await?
 }
 pub async fn call_connector_api(
+    proxy: &Proxy,
     request: Request,
 ) -> CustomResult<Result<Response, Response>, ApiClientError> {
-    let proxy_url = Some("http://localhost:8081");
-    let client = create_client(proxy_url)?;
     let url =
         reqwest::Url::parse(&request.url).change_context(ApiClientError::UrlEncodingFailed)?;
+    let should_bypass_proxy = url
+        .host_str()
+        .is_some_and(|host| proxy.bypass_proxy_urls.iter().any(|bypass| bypass == host));
+    let client = create_client(proxy, should_bypass_proxy, &request)?;
+    let headers = request.headers.construct_header_map()?;
 
-    let request: reqwest::RequestBuilder = {
-        match request.method {
-            Method::Get => client.get(url),
-            Method::Post => {
-                let client = client.post(url);
-                match request.body {
-                    Some(RequestContent::Json(payload)) => client.json(&payload),
-                    Some(RequestContent::FormUrlEncoded(payload)) => client.form(&payload),
-                    Some(RequestContent::Xml(payload)) => {
-                        // For XML content, we need to extract the XML string properly
-                        // The payload implements a custom Serialize that generates XML content
-                        let body = serde_json::to_string(&payload)
-                            .change_context(ApiClientError::UrlEncodingFailed)?;
-
-                        // Properly deserialize the JSON string to extract clean XML
-                        let xml_body = if body.starts_with('"') && body.ends_with('"') {
-                            // This is a JSON-encoded string, deserialize it properly
-                            serde_json::from_str::<String>(&body)
-                                .change_context(ApiClientError::UrlEncodingFailed)?
-                        } else {
-                            // This is already the raw body content
-                            body
-                        };
-                        client.body(xml_body).header("Content-Type", "text/xml")
-                    }
-                    Some(RequestContent::FormData(form)) => client.multipart(form),
-                    Some(RequestContent::RawBytes(payload)) => client.body(payload),
-                    _ => client,
-                }
-            }
+    let method = match request.method {
+        Method::Get => reqwest::Method::GET,
+        Method::Post => reqwest::Method::POST,
+        Method::Put => reqwest::Method::PUT,
+        Method::Patch => reqwest::Method::PATCH,
+        Method::Delete => reqwest::Method::DELETE,
+    };
+    let mut client = client.request(method, url);
+    if !request.query.is_empty() {
+        client = client.query(&request.query);
+    }
+
+    let request: reqwest::RequestBuilder = match request.body {
+        Some(RequestContent::Json(payload)) => client.json(&payload),
+        Some(RequestContent::FormUrlEncoded(payload)) => client.form(&payload),
+        Some(RequestContent::Xml(payload)) => {
+            // For XML content, we need to extract the XML string properly
+            // The payload implements a custom Serialize that generates XML content
+            let body = serde_json::to_string(&payload)
+                .change_context(ApiClientError::UrlEncodingFailed)?;
+
+            // Properly deserialize the JSON string to extract clean XML
+            let xml_body = if body.starts_with('"') && body.ends_with('"') {
+                // This is a JSON-encoded string, deserialize it properly
+                serde_json::from_str::<String>(&body)
+                    .change_context(ApiClientError::UrlEncodingFailed)?
+            } else {
+                // This is already the raw body content
+                body
+            };
+            client.body(xml_body).header("Content-Type", "text/xml")
         }
+        Some(RequestContent::FormData(form)) => client.multipart(form),
+        Some(RequestContent::RawBytes(payload)) => client.body(payload),
+        None => client,
     };
 
     let send_request = async {
-        request.send().await.map_err(|error| {
+        request.headers(headers).send().await.map_err(|error| {
             let api_error = match error {
+                error if error.is_connect() => ApiClientError::ConnectionFailed,
                 error if error.is_timeout() => ApiClientError::RequestTimeoutReceived,
+                error if is_connection_closed_before_message_could_complete(&error) => {
+                    ApiClientError::ConnectionClosedIncompleteMessage
+                }
                 _ => ApiClientError::RequestNotSent(error.to_string()),
             };
 
@@ -227,17 +463,82 @@ pub async fn call_connector_api(
     let response = send_request.await;
     handle_response(response).await
 }
+fn is_connection_closed_before_message_could_complete(error: &reqwest::Error) -> bool {
+    let mut source = std::error::Error::source(error);
+    while let Some(error) = source {
+        if error
+            .to_string()
+            .contains("connection closed before message completed")
+        {
+            return true;
+        }
+        source = error.source();
+    }
+    false
+}
+
+/// Which failed connector calls of a flow are retried, bounded by `Proxy::retry`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RetryMode {
+    Never,
+    /// Only calls that never reached the connector, safe for flows that aren't idempotent
+    ConnectionErrors,
+    /// Also timeouts, dropped connections and 502/503/504 responses, for idempotent flows
+    TransientErrors,
+}
+
+/// The failure to retry the call on, `None` when the response has to be handled as is
+fn retryable_failure(
+    response: &CustomResult<Result<Response, Response>, ApiClientError>,
+    retry_mode: RetryMode,
+) -> Option<ApiClientError> {
+    let failure = match response {
+        Ok(Ok(_)) => return None,
+        Ok(Err(body)) => match body.status_code {
+            502 => ApiClientError::BadGatewayReceived,
+            503 => ApiClientError::ServiceUnavailableReceived,
+            504 => ApiClientError::GatewayTimeoutReceived,
+            _ => return None,
+        },
+        Err(error) => error.current_context().clone(),
+    };
+    let retryable = match (retry_mode, &failure) {
+        (RetryMode::Never, _) => false,
+        (_, ApiClientError::ConnectionFailed) => true,
+        (
+            RetryMode::TransientErrors,
+            ApiClientError::RequestTimeoutReceived
+            | ApiClientError::ConnectionClosedIncompleteMessage
+            | ApiClientError::BadGatewayReceived
+            | ApiClientError::ServiceUnavailableReceived
+            | ApiClientError::GatewayTimeoutReceived,
+        ) => true,
+        _ => false,
+    };
+    retryable.then_some(failure)
+}
+
+/// Exponential backoff with full jitter, so callers retrying together don't stay in step
+fn retry_delay(retry: &ConnectorRetry, attempt: u32) -> Duration {
+    let backoff = retry
+        .base_delay_ms
+        .saturating_mul(2_u64.saturating_pow(attempt.saturating_sub(1)))
+        .min(retry.max_delay_ms);
+    Duration::from_millis(rand::thread_rng().gen_range(0..=backoff))
+}
+
 pub async fn execute_connector_processing_step<F, ResourceCommonData, Req, Resp>(
-    // proxy: &Proxy,
+    proxy: &Proxy,
     connector: BoxedConnectorIntegrationV2<'static, F, ResourceCommonData, Req, Resp>,
     router_data: RouterDataV2<F, ResourceCommonData, Req, Resp>,
     all_keys_required: Option<bool>,
     // event_params: EventProcessingParams<'_>,
     // token_data: Option<TokenData>,
     call_connector_action: common_enums::CallConnectorAction,
+    retry_mode: RetryMode,
     // test_context: Option<TestContext>,
     // api_tag: Option<String>,
-) -> CustomResult<(), ConnectorError>
+) -> CustomResult<RouterDataV2<F, ResourceCommonData, Req, Resp>, ConnectorError>
 where
     F: Clone + 'static,
     // T: FlowIntegrity,
@@ -248,31 +549,273 @@ where
                                          //     + ConnectorRequestReference
                                          //     + AdditionalHeaders,
 {
-    let start = tokio::time::Instant::now();
     let result = match call_connector_action {
         common_enums::CallConnectorAction::Trigger => {
-            let mut connector_request = connector.build_request_v2(&router_data.clone())?;
+            let mut attempt = 1;
+            // request bodies can't be cloned, so every attempt builds its request anew
+            let response = loop {
+                let Some(request) = connector.build_request_v2(&router_data)? else {
+                    break None;
+                };
+                let url = request.url.clone();
+                let method = request.method;
+                tracing::debug!(
+                    connector_request =
+                        %extract_raw_connector_request(&request, RequestCapture::Masked),
+                    attempt,
+                    "sending connector request"
+                );
 
-            let _ = match connector_request.as_ref() {
-                Some(request) => {
-                    let raw = extract_raw_connector_request(&request);
-                    println!("{:?} RAW REQUEST", raw);
+                let start = tokio::time::Instant::now();
+                let response = call_connector_api(proxy, request).await;
+                let latency_ms = u64::try_from(start.elapsed().as_millis()).unwrap_or(u64::MAX);
+                match &response {
+                    Ok(Ok(body)) | Ok(Err(body)) => tracing::info!(
+                        url = %url,
+                        %method,
+                        attempt,
+                        status_code = body.status_code,
+                        latency_ms,
+                        "connector call completed"
+                    ),
+                    Err(error) => tracing::error!(
+                        url = %url,
+                        %method,
+                        attempt,
+                        latency_ms,
+                        ?error,
+                        "connector call failed"
+                    ),
                 }
-                None => (),
-            };
 
-            let _ = match connector_request {
-                Some(request) => {
-                    let response = call_connector_api(request)
-                        .await
-                        .change_context(ConnectorError::RequestEncodingFailed)
-                        .inspect_err(|err| {
-                            println!("{} NETWORK ERROR", err);
-                        });
+                match retryable_failure(&response, retry_mode) {
+                    Some(failure) if attempt < proxy.retry.max_attempts => {
+                        let delay = retry_delay(&proxy.retry, attempt);
+                        tracing::warn!(
+                            url = %url,
+                            %method,
+                            attempt,
+                            error = %failure,
+                            delay_ms = u64::try_from(delay.as_millis()).unwrap_or(u64::MAX),
+                            "retrying connector call"
+                        );
+                        tokio::time::sleep(delay).await;
+                        attempt += 1;
+                    }
+                    _ => break Some(response),
                 }
-                None => (),
             };
+
+            match response {
+                Some(response) => {
+                    let response = response
+                        .change_context(ConnectorError::ProcessingStepFailed(None))
+                        .attach_printable_lazy(|| {
+                            format!("Connector call failed after {attempt} attempt(s)")
+                        })?;
+                    match response {
+                        Ok(body) => connector.handle_response_v2(&router_data, body)?,
+                        Err(body) => {
+                            let error = match body.status_code {
+                                500..=511 => connector.get_5xx_error_response(body)?,
+                                _ => connector.get_error_response_v2(body)?,
+                            };
+                            RouterDataV2 {
+                                response: Err(error),
+                                ..router_data
+                            }
+                        }
+                    }
+                }
+                None => router_data,
+            }
         }
     };
     Ok(result)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request_with_identity(certificate: Option<&str>, certificate_key: Option<&str>) -> Request {
+        let mut request = Request::new(Method::Post, "https://example.com/payments");
+        request.add_certificate(certificate.map(|pem| Secret::new(pem.to_string())));
+        request.add_certificate_key(certificate_key.map(|pem| Secret::new(pem.to_string())));
+        request
+    }
+
+    fn cache_key(request: &Request) -> ClientCacheKey {
+        ClientCacheKey::new(&Proxy::default(), false, request)
+    }
+
+    fn proxy_cache_key(http_url: String) -> ClientCacheKey {
+        let proxy = Proxy {
+            http_url: Some(http_url),
+            ..Proxy::default()
+        };
+        ClientCacheKey::new(&proxy, false, &request_with_identity(None, None))
+    }
+
+    #[test]
+    fn separates_clients_by_identity() {
+        let without_identity = cache_key(&request_with_identity(None, None));
+        let merchant_a = cache_key(&request_with_identity(Some("cert-a"), Some("key-a")));
+        let merchant_b = cache_key(&request_with_identity(Some("cert-b"), Some("key-b")));
+
+        assert!(without_identity.identity_fingerprint.is_none());
+        assert!(merchant_a != without_identity);
+        assert!(merchant_a != merchant_b);
+        assert!(merchant_a == cache_key(&request_with_identity(Some("cert-a"), Some("key-a"))));
+        // the same bytes split differently between certificate and key are another identity
+        assert!(cache_key(&request_with_identity(Some("cert-ak"), Some("ey-a"))) != merchant_a);
+    }
+
+    #[test]
+    fn evicts_the_oldest_client_when_full() {
+        let client = Client::new();
+        let started_at = Instant::now();
+        let mut clients: HashMap<ClientCacheKey, CachedClient> = (0..MAX_CACHED_CLIENTS)
+            .map(|index| {
+                (
+                    proxy_cache_key(format!("http://proxy-{index}:8080")),
+                    CachedClient {
+                        client: client.clone(),
+                        created_at: started_at
+                            + Duration::from_secs(u64::try_from(index).unwrap_or(u64::MAX)),
+                    },
+                )
+            })
+            .collect();
+
+        let newest = proxy_cache_key("http://proxy-new:8080".to_string());
+        cache_client(&mut clients, newest.clone(), client.clone());
+
+        assert_eq!(clients.len(), MAX_CACHED_CLIENTS);
+        assert!(clients.contains_key(&newest));
+        assert!(!clients.contains_key(&proxy_cache_key("http://proxy-0:8080".to_string())));
+        assert!(clients.contains_key(&proxy_cache_key("http://proxy-1:8080".to_string())));
+    }
+
+    fn error_response(
+        status_code: u16,
+    ) -> CustomResult<Result<Response, Response>, ApiClientError> {
+        Ok(Err(Response {
+            headers: None,
+            response: Default::default(),
+            status_code,
+        }))
+    }
+
+    #[test]
+    fn retries_only_what_the_mode_allows() {
+        let connection_failed = || Err(report!(ApiClientError::ConnectionFailed));
+        let timed_out = || Err(report!(ApiClientError::RequestTimeoutReceived));
+        let dropped = || Err(report!(ApiClientError::ConnectionClosedIncompleteMessage));
+
+        for mode in [RetryMode::ConnectionErrors, RetryMode::TransientErrors] {
+            assert_eq!(
+                retryable_failure(&connection_failed(), mode),
+                Some(ApiClientError::ConnectionFailed)
+            );
+        }
+        assert_eq!(
+            retryable_failure(&timed_out(), RetryMode::TransientErrors),
+            Some(ApiClientError::RequestTimeoutReceived)
+        );
+        assert_eq!(
+            retryable_failure(&dropped(), RetryMode::TransientErrors),
+            Some(ApiClientError::ConnectionClosedIncompleteMessage)
+        );
+        // the connector may have acted on these, a flow that isn't idempotent can't send it again
+        assert_eq!(
+            retryable_failure(&timed_out(), RetryMode::ConnectionErrors),
+            None
+        );
+        assert_eq!(
+            retryable_failure(&dropped(), RetryMode::ConnectionErrors),
+            None
+        );
+        assert_eq!(
+            retryable_failure(&connection_failed(), RetryMode::Never),
+            None
+        );
+    }
+
+    #[test]
+    fn retries_gateway_errors_of_idempotent_flows() {
+        assert_eq!(
+            retryable_failure(&error_response(502), RetryMode::TransientErrors),
+            Some(ApiClientError::BadGatewayReceived)
+        );
+        assert_eq!(
+            retryable_failure(&error_response(503), RetryMode::TransientErrors),
+            Some(ApiClientError::ServiceUnavailableReceived)
+        );
+        assert_eq!(
+            retryable_failure(&error_response(504), RetryMode::TransientErrors),
+            Some(ApiClientError::GatewayTimeoutReceived)
+        );
+        assert_eq!(
+            retryable_failure(&error_response(503), RetryMode::ConnectionErrors),
+            None
+        );
+        for status_code in [400, 422, 500] {
+            assert_eq!(
+                retryable_failure(&error_response(status_code), RetryMode::TransientErrors),
+                None
+            );
+        }
+        let success = Ok(Ok(Response {
+            headers: None,
+            response: Default::default(),
+            status_code: 200,
+        }));
+        assert_eq!(
+            retryable_failure(&success, RetryMode::TransientErrors),
+            None
+        );
+    }
+
+    #[test]
+    fn backs_off_exponentially_up_to_the_max_delay() {
+        let retry = ConnectorRetry {
+            max_attempts: 10,
+            base_delay_ms: 100,
+            max_delay_ms: 1000,
+        };
+        for (attempt, bound_ms) in [
+            (1, 100),
+            (2, 200),
+            (3, 400),
+            (4, 800),
+            (5, 1000),
+            (40, 1000),
+        ] {
+            for _ in 0..50 {
+                assert!(retry_delay(&retry, attempt) <= Duration::from_millis(bound_ms));
+            }
+        }
+        let no_delay = ConnectorRetry {
+            base_delay_ms: 0,
+            ..retry
+        };
+        assert_eq!(retry_delay(&no_delay, 3), Duration::ZERO);
+    }
+
+    #[test]
+    fn keeps_the_client_cached_first() {
+        let key = proxy_cache_key("http://proxy:8080".to_string());
+        let mut clients = HashMap::new();
+        cache_client(&mut clients, key.clone(), Client::new());
+        let created_at = clients.get(&key).map(|cached| cached.created_at);
+
+        cache_client(&mut clients, key.clone(), Client::new());
+
+        assert_eq!(clients.len(), 1);
+        assert_eq!(
+            clients.get(&key).map(|cached| cached.created_at),
+            created_at
+        );
+    }
+}
@@ -1,7 +1,42 @@
 use serde::{Deserialize, Serialize};
 use strum::Display;
-#[derive(Debug, Serialize, Deserialize, Display)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
 pub enum PaymentStatus {
-    #[strum(serialize = "pending")]
+    Started,
+    AuthenticationFailed,
+    RouterDeclined,
+    AuthenticationPending,
+    AuthenticationSuccessful,
+    Authorized,
+    AuthorizationFailed,
+    Charged,
+    Authorizing,
+    CodInitiated,
+    Voided,
+    VoidInitiated,
+    CaptureInitiated,
+    CaptureFailed,
+    VoidFailed,
+    AutoRefunded,
+    PartialCharged,
+    PartialChargedAndChargeable,
+    Unresolved,
     Pending,
+    Failure,
+    PaymentMethodAwaited,
+    ConfirmationAwaited,
+    DeviceDataCollectionPending,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum RefundStatus {
+    Failure,
+    ManualReview,
+    Pending,
+    Success,
+    TransactionFailure,
 }
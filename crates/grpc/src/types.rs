@@ -1,22 +1,202 @@
+use crate::payments::{PaymentStatus, RefundStatus};
+use hyperswitch_masking::Secret;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::fmt::Debug;
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct CardDetails {
-    pub card_number: i64,
-    pub card_cvc: i64,
+    pub card_number: Secret<String>,
+    pub card_exp_month: Option<Secret<String>>,
+    pub card_exp_year: Option<Secret<String>>,
+    pub card_cvc: Option<Secret<String>>,
+    pub card_holder_name: Option<Secret<String>>,
     pub card_issuer: Option<String>,
+    pub card_network: Option<String>,
+    pub card_type: Option<String>,
+    pub card_issuing_country: Option<String>,
+    pub nick_name: Option<Secret<String>>,
+    pub co_badged_card_data: Option<CoBadgedCardData>,
+}
+
+// Networks other than the primary one that a card can also be processed on
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct CoBadgedCardData {
+    pub co_badged_card_networks: Vec<String>,
+    pub issuer_country_code: Option<String>,
+    pub is_regulated: bool,
+    pub regulated_name: Option<String>,
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
 #[serde(rename_all = "snake_case")]
 pub enum PaymentMethod {
     Card(CardDetails),
+    // Card whose number is a vault token, resolved by the vault proxy on the way to the connector
+    CardProxy(CardDetails),
+}
+
+// Browser the customer is paying from, used by connectors for risk checks and 3DS
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct BrowserInformation {
+    pub color_depth: Option<u8>,
+    pub java_enabled: Option<bool>,
+    pub java_script_enabled: Option<bool>,
+    pub language: Option<String>,
+    pub screen_height: Option<u32>,
+    pub screen_width: Option<u32>,
+    pub time_zone: Option<i32>,
+    pub ip_address: Option<String>,
+    pub accept_header: Option<String>,
+    pub user_agent: Option<String>,
 }
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct Address {
+    pub first_name: Option<Secret<String>>,
+    pub last_name: Option<Secret<String>>,
+    pub line1: Option<Secret<String>>,
+    pub line2: Option<Secret<String>>,
+    pub line3: Option<Secret<String>>,
+    pub city: Option<String>,
+    pub state: Option<Secret<String>>,
+    pub zip: Option<Secret<String>>,
+    // ISO 3166-1 alpha-2 country code
+    pub country: Option<String>,
+}
+
 // Authorize request type
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct PaymentServiceAuthorizeRequest {
+    pub request_ref_id: String,
     pub amount: i64,
     pub minor_amount: i64,
+    pub currency: String,
     pub payment_method: PaymentMethod,
+    pub return_url: Option<String>,
+    pub customer_id: Option<String>,
+    pub capture_method: Option<String>,
+    pub setup_future_usage: Option<String>,
+    pub off_session: Option<bool>,
+    pub browser_info: Option<BrowserInformation>,
+    pub billing_address: Option<Address>,
+}
+
+// Redirection the customer has to follow to complete the payment
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "snake_case")]
+pub enum RedirectForm {
+    Form {
+        endpoint: String,
+        method: String,
+        form_fields: HashMap<String, String>,
+    },
+}
+
+// Authorize response type
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct PaymentServiceAuthorizeResponse {
+    pub status: PaymentStatus,
+    pub connector_transaction_id: Option<String>,
+    pub connector_response_reference_id: Option<String>,
+    pub redirection_data: Option<RedirectForm>,
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+    pub error_reason: Option<String>,
+    pub raw_connector_status_code: u16,
+}
+
+// Sync request type
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct PaymentServiceGetRequest {
+    pub request_ref_id: String,
+    pub connector_transaction_id: String,
+    pub encoded_data: Option<String>,
+}
+
+// Sync response type
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct PaymentServiceGetResponse {
+    pub status: PaymentStatus,
+    pub connector_transaction_id: Option<String>,
+    pub connector_response_reference_id: Option<String>,
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+    pub error_reason: Option<String>,
+    pub raw_connector_status_code: u16,
+}
+
+// Capture request type
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct PaymentServiceCaptureRequest {
+    pub request_ref_id: String,
+    pub connector_transaction_id: String,
+    pub amount_to_capture: i64,
+    pub minor_amount_to_capture: i64,
+    pub currency: String,
+}
+
+// Capture response type
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct PaymentServiceCaptureResponse {
+    pub status: PaymentStatus,
+    pub connector_transaction_id: Option<String>,
+    pub connector_response_reference_id: Option<String>,
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+    pub error_reason: Option<String>,
+    pub raw_connector_status_code: u16,
+}
+
+// Void request type
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct PaymentServiceVoidRequest {
+    pub request_ref_id: String,
+    pub connector_transaction_id: String,
+    pub cancellation_reason: Option<String>,
+}
+
+// Void response type
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct PaymentServiceVoidResponse {
+    pub status: PaymentStatus,
+    pub connector_transaction_id: Option<String>,
+    pub connector_response_reference_id: Option<String>,
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+    pub error_reason: Option<String>,
+    pub raw_connector_status_code: u16,
+}
+
+// Refund request type
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct PaymentServiceRefundRequest {
+    pub request_ref_id: String,
+    pub refund_id: String,
+    pub connector_transaction_id: String,
+    pub currency: String,
+    pub payment_amount: i64,
+    pub refund_amount: i64,
+    pub minor_refund_amount: i64,
+    pub reason: Option<String>,
+}
+
+// Refund sync request type
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct RefundServiceGetRequest {
+    pub request_ref_id: String,
+    pub connector_transaction_id: String,
+    pub connector_refund_id: String,
+    pub reason: Option<String>,
+}
+
+// Refund and refund sync response type
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct RefundServiceResponse {
+    pub refund_status: RefundStatus,
+    pub connector_refund_id: Option<String>,
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+    pub error_reason: Option<String>,
+    pub raw_connector_status_code: u16,
 }
@@ -1,8 +1,12 @@
 use common_utils::errors::CustomResult;
 use common_utils::request::{Method, Request, RequestBuilder, RequestContent};
 use domain_types::errors;
+use domain_types::router_data::ErrorResponse;
 use domain_types::router_data_v2::RouterDataV2;
-use hyperswitch_masking::Maskable;
+use domain_types::router_response_types::Response;
+use hyperswitch_masking::{Maskable, Secret};
+const CONTENT_TYPE: &str = "Content-Type";
+
 pub type BoxedConnectorIntegrationV2<'a, Flow, ResourceCommonData, Req, Resp> =
     Box<&'a (dyn ConnectorIntegrationV2<Flow, ResourceCommonData, Req, Resp> + Send + Sync)>;
 pub trait ConnectorIntegrationAnyV2<Flow, ResourceCommonData, Req, Resp>:
@@ -37,6 +41,19 @@ pub trait ConnectorIntegrationV2<Flow, ResourceCommonData, Req, Resp>:
         Ok(String::new())
     }
 
+    /// query parameters url encoded onto the url returned by `get_url`
+    fn get_query_params(
+        &self,
+        _req: &RouterDataV2<Flow, ResourceCommonData, Req, Resp>,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        Ok(vec![])
+    }
+
+    /// content type of the body returned by `get_request_body`, sent unless `get_headers` sets one
+    fn get_content_type(&self) -> &'static str {
+        "application/json"
+    }
+
     // returns request body
     fn get_request_body(
         &self,
@@ -45,26 +62,119 @@ pub trait ConnectorIntegrationV2<Flow, ResourceCommonData, Req, Resp>:
         Ok(None)
     }
 
-    /// builds the request and returns it
+    /// root certificate the connector's endpoint is signed with, when it isn't publicly trusted
+    fn get_ca_certificate(
+        &self,
+        _req: &RouterDataV2<Flow, ResourceCommonData, Req, Resp>,
+    ) -> CustomResult<Option<Secret<String>>, errors::ConnectorError> {
+        Ok(None)
+    }
+
+    /// client certificate (PEM) presented to connectors that authenticate with mutual TLS
+    fn get_certificate(
+        &self,
+        _req: &RouterDataV2<Flow, ResourceCommonData, Req, Resp>,
+    ) -> CustomResult<Option<Secret<String>>, errors::ConnectorError> {
+        Ok(None)
+    }
+
+    /// private key (PEM) of the certificate returned by `get_certificate`
+    fn get_certificate_key(
+        &self,
+        _req: &RouterDataV2<Flow, ResourceCommonData, Req, Resp>,
+    ) -> CustomResult<Option<Secret<String>>, errors::ConnectorError> {
+        Ok(None)
+    }
+
+    /// speak HTTP/2 without negotiating it first, only for connectors known to support it
+    fn get_http2_prior_knowledge(
+        &self,
+        _req: &RouterDataV2<Flow, ResourceCommonData, Req, Resp>,
+    ) -> bool {
+        false
+    }
+
+    /// builds the request and returns it, `None` when there is nothing to send
     fn build_request_v2(
         &self,
         req: &RouterDataV2<Flow, ResourceCommonData, Req, Resp>,
     ) -> CustomResult<Option<Request>, domain_types::errors::ConnectorError> {
-        println!("{:?}", self.get_request_body(req));
-        Ok(Some(
-            RequestBuilder::new()
-                .method(self.get_http_method())
-                .url(self.get_url(req)?.as_str())
-                .attach_default_headers()
-                .headers(self.get_headers(req)?)
-                .set_optional_body(self.get_request_body(req)?)
-                // .add_certificate(self.get_certificate(req)?)
-                // .add_certificate_key(self.get_certificate_key(req)?)
-                .build(),
-        ))
+        build_default_request(self, req).map(Some)
+    }
+
+    /// accepts the raw api response and decodes it
+    fn handle_response_v2(
+        &self,
+        data: &RouterDataV2<Flow, ResourceCommonData, Req, Resp>,
+        _res: Response,
+    ) -> CustomResult<RouterDataV2<Flow, ResourceCommonData, Req, Resp>, errors::ConnectorError>
+    where
+        Flow: Clone,
+        ResourceCommonData: Clone,
+        Req: Clone,
+        Resp: Clone,
+    {
+        Ok(data.clone())
+    }
+
+    /// accepts the raw api error response and decodes it
+    fn get_error_response_v2(
+        &self,
+        res: Response,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        Ok(ErrorResponse {
+            status_code: res.status_code,
+            ..ErrorResponse::get_not_implemented()
+        })
+    }
+
+    /// accepts the raw 5xx error response and decodes it
+    fn get_5xx_error_response(
+        &self,
+        res: Response,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        self.get_error_response_v2(res)
     }
 }
 
+/// Request assembled from the connector's url, query, headers, body and certificates, for
+/// connectors overriding `build_request_v2` only to decide whether a request is sent at all
+pub fn build_default_request<C, Flow, ResourceCommonData, Req, Resp>(
+    connector: &C,
+    req: &RouterDataV2<Flow, ResourceCommonData, Req, Resp>,
+) -> CustomResult<Request, errors::ConnectorError>
+where
+    C: ConnectorIntegrationV2<Flow, ResourceCommonData, Req, Resp> + ?Sized,
+{
+    let mut headers = connector.get_headers(req)?;
+    let body = connector.get_request_body(req)?;
+    // multipart bodies carry their own content type with the boundary
+    let needs_content_type = body
+        .as_ref()
+        .is_some_and(|body| !matches!(body, RequestContent::FormData(_)))
+        && !headers
+            .iter()
+            .any(|(name, _)| name.eq_ignore_ascii_case(CONTENT_TYPE));
+    if needs_content_type {
+        headers.push((
+            CONTENT_TYPE.to_string(),
+            connector.get_content_type().to_string().into(),
+        ));
+    }
+    Ok(RequestBuilder::new()
+        .method(connector.get_http_method())
+        .url(connector.get_url(req)?.as_str())
+        .query(connector.get_query_params(req)?)
+        .attach_default_headers()
+        .headers(headers)
+        .set_optional_body(body)
+        .add_ca_certificate_pem(connector.get_ca_certificate(req)?)
+        .add_certificate(connector.get_certificate(req)?)
+        .add_certificate_key(connector.get_certificate_key(req)?)
+        .http2_prior_knowledge(connector.get_http2_prior_knowledge(req))
+        .build())
+}
+
 impl<S, Flow, ResourceCommonData, Req, Resp>
     ConnectorIntegrationAnyV2<Flow, ResourceCommonData, Req, Resp> for S
 where
@@ -76,3 +186,77 @@ where
         Box::new(self)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use domain_types::router_data_v2::ConnectorAuthType;
+
+    struct DefaultConnector;
+
+    impl ConnectorIntegrationV2<(), (), (), ()> for DefaultConnector {}
+
+    struct QueryConnector;
+
+    impl ConnectorIntegrationV2<(), (), (), ()> for QueryConnector {
+        fn get_http_method(&self) -> Method {
+            Method::Get
+        }
+
+        fn get_url(
+            &self,
+            _req: &RouterDataV2<(), (), (), ()>,
+        ) -> CustomResult<String, errors::ConnectorError> {
+            Ok("https://connector.example.com/v1/payments".to_string())
+        }
+
+        fn get_query_params(
+            &self,
+            _req: &RouterDataV2<(), (), (), ()>,
+        ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+            Ok(vec![
+                ("merchantAccount".to_string(), "TestMerchant".to_string()),
+                ("shopperReference".to_string(), "cus 123".to_string()),
+            ])
+        }
+    }
+
+    fn router_data() -> RouterDataV2<(), (), (), ()> {
+        RouterDataV2 {
+            flow: std::marker::PhantomData,
+            resource_common_data: (),
+            connector_auth_type: ConnectorAuthType::TemporaryAuth,
+            request: (),
+            response: Ok(()),
+        }
+    }
+
+    #[test]
+    fn adds_the_connector_query_params() {
+        let request = QueryConnector
+            .build_request_v2(&router_data())
+            .ok()
+            .flatten()
+            .expect("the default builder always builds a request");
+
+        assert_eq!(request.url, "https://connector.example.com/v1/payments");
+        assert_eq!(
+            request.query,
+            vec![
+                ("merchantAccount".to_string(), "TestMerchant".to_string()),
+                ("shopperReference".to_string(), "cus 123".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn sends_no_query_by_default() {
+        let request = DefaultConnector
+            .build_request_v2(&router_data())
+            .ok()
+            .flatten()
+            .expect("the default builder always builds a request");
+
+        assert!(request.query.is_empty());
+    }
+}
@@ -2,7 +2,11 @@ use domain_types::payment_method::PaymentMethodDataTypes;
 
 use crate::connector_integration_v2::ConnectorIntegrationV2;
 use domain_types::connector_flow;
-use domain_types::connector_types::{PaymentFlowData, PaymentsAuthorizeData, PaymentsResponseData};
+use domain_types::connector_types::{
+    PaymentFlowData, PaymentVoidData, PaymentsAuthorizeData, PaymentsCaptureData,
+    PaymentsResponseData, PaymentsSyncData, RefundFlowData, RefundSyncData, RefundsData,
+    RefundsResponseData,
+};
 
 pub trait PaymentAuthorizeV2<T: PaymentMethodDataTypes>:
     ConnectorIntegrationV2<
@@ -13,6 +17,45 @@ pub trait PaymentAuthorizeV2<T: PaymentMethodDataTypes>:
 >
 {
 }
-pub trait ConnectorServiceTrait<T: PaymentMethodDataTypes>: PaymentAuthorizeV2<T> {}
+
+pub trait PaymentSyncV2:
+    ConnectorIntegrationV2<
+    connector_flow::PSync,
+    PaymentFlowData,
+    PaymentsSyncData,
+    PaymentsResponseData,
+>
+{
+}
+
+pub trait PaymentCaptureV2:
+    ConnectorIntegrationV2<
+    connector_flow::Capture,
+    PaymentFlowData,
+    PaymentsCaptureData,
+    PaymentsResponseData,
+>
+{
+}
+
+pub trait PaymentVoidV2:
+    ConnectorIntegrationV2<connector_flow::Void, PaymentFlowData, PaymentVoidData, PaymentsResponseData>
+{
+}
+
+pub trait RefundV2:
+    ConnectorIntegrationV2<connector_flow::Refund, RefundFlowData, RefundsData, RefundsResponseData>
+{
+}
+
+pub trait RefundSyncV2:
+    ConnectorIntegrationV2<connector_flow::RSync, RefundFlowData, RefundSyncData, RefundsResponseData>
+{
+}
+
+pub trait ConnectorServiceTrait<T: PaymentMethodDataTypes>:
+    PaymentAuthorizeV2<T> + PaymentSyncV2 + PaymentCaptureV2 + PaymentVoidV2 + RefundV2 + RefundSyncV2
+{
+}
 
 pub type BoxedConnector<T> = Box<&'static (dyn ConnectorServiceTrait<T> + Sync)>;
@@ -1,27 +1,207 @@
 use crate::payment::{Payment, PaymentOperationInternal, PaymentService};
+use domain_types::{
+    connector_types::ConnectorEnum, errors::ApplicationErrorResponse,
+    router_data_v2::ConnectorAuthType, utils::ForeignFrom,
+};
 use error_stack::Result as ErrorStackResult;
-use grpc::types::PaymentServiceAuthorizeRequest;
+use grpc::types::{
+    PaymentServiceAuthorizeRequest, PaymentServiceAuthorizeResponse, PaymentServiceCaptureRequest,
+    PaymentServiceCaptureResponse, PaymentServiceGetRequest, PaymentServiceGetResponse,
+    PaymentServiceRefundRequest, PaymentServiceVoidRequest, PaymentServiceVoidResponse,
+    RefundServiceGetRequest, RefundServiceResponse,
+};
 
-// Error types for vehicle operations
-#[derive(Debug)]
-pub struct PaymentServiceAuthorizeError {
-    pub message: String,
-}
+pub type CustomResult<T, E> = ErrorStackResult<T, E>;
 
-impl std::fmt::Display for PaymentServiceAuthorizeError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.message)
-    }
-}
+pub type PaymentAuthrorizeResponse = PaymentServiceAuthorizeResponse;
 
-impl std::error::Error for PaymentServiceAuthorizeError {}
-pub type CustomResult<T, E> = ErrorStackResult<T, E>;
+pub type PaymentSyncResponse = PaymentServiceGetResponse;
+
+pub type PaymentCaptureResponse = PaymentServiceCaptureResponse;
+
+pub type PaymentVoidResponse = PaymentServiceVoidResponse;
 
-pub type PaymentAuthrorizeResponse = String;
+pub type RefundResponse = RefundServiceResponse;
 
 pub async fn payment_authorize(
+    payment: &Payment,
+    connector: ConnectorEnum,
+    connector_auth_type: ConnectorAuthType,
     payload: PaymentServiceAuthorizeRequest,
-) -> CustomResult<PaymentAuthrorizeResponse, PaymentServiceAuthorizeError> {
-    let _ = Payment.authorize(payload).await;
-    Ok("success".to_string())
+) -> CustomResult<PaymentAuthrorizeResponse, ApplicationErrorResponse> {
+    payment
+        .authorize(payload, connector, connector_auth_type)
+        .await
+        .map_err(|err| error_stack::report!(ApplicationErrorResponse::from(err)))
+}
+
+pub async fn payment_sync(
+    payment: &Payment,
+    connector: ConnectorEnum,
+    connector_auth_type: ConnectorAuthType,
+    payload: PaymentServiceGetRequest,
+) -> CustomResult<PaymentSyncResponse, ApplicationErrorResponse> {
+    payment
+        .sync(payload, connector, connector_auth_type)
+        .await
+        .map_err(|err| error_stack::report!(ApplicationErrorResponse::from(err)))
+}
+
+pub async fn payment_capture(
+    payment: &Payment,
+    connector: ConnectorEnum,
+    connector_auth_type: ConnectorAuthType,
+    payload: PaymentServiceCaptureRequest,
+) -> CustomResult<PaymentCaptureResponse, ApplicationErrorResponse> {
+    let router_data = payment
+        .capture(payload, connector, connector_auth_type)
+        .await
+        .map_err(|err| error_stack::report!(ApplicationErrorResponse::from(err)))?;
+    Ok(PaymentServiceCaptureResponse::foreign_from(router_data))
+}
+
+pub async fn payment_void(
+    payment: &Payment,
+    connector: ConnectorEnum,
+    connector_auth_type: ConnectorAuthType,
+    payload: PaymentServiceVoidRequest,
+) -> CustomResult<PaymentVoidResponse, ApplicationErrorResponse> {
+    let router_data = payment
+        .void(payload, connector, connector_auth_type)
+        .await
+        .map_err(|err| error_stack::report!(ApplicationErrorResponse::from(err)))?;
+    Ok(PaymentServiceVoidResponse::foreign_from(router_data))
+}
+
+pub async fn payment_refund(
+    payment: &Payment,
+    connector: ConnectorEnum,
+    connector_auth_type: ConnectorAuthType,
+    payload: PaymentServiceRefundRequest,
+) -> CustomResult<RefundResponse, ApplicationErrorResponse> {
+    let router_data = payment
+        .refund(payload, connector, connector_auth_type)
+        .await
+        .map_err(|err| error_stack::report!(ApplicationErrorResponse::from(err)))?;
+    Ok(RefundServiceResponse::foreign_from(router_data))
+}
+
+pub async fn refund_sync(
+    payment: &Payment,
+    connector: ConnectorEnum,
+    connector_auth_type: ConnectorAuthType,
+    payload: RefundServiceGetRequest,
+) -> CustomResult<RefundResponse, ApplicationErrorResponse> {
+    let router_data = payment
+        .refund_sync(payload, connector, connector_auth_type)
+        .await
+        .map_err(|err| error_stack::report!(ApplicationErrorResponse::from(err)))?;
+    Ok(RefundServiceResponse::foreign_from(router_data))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use domain_types::types::{Connectors, Proxy};
+    use grpc::types::{CardDetails, PaymentMethod};
+    use hyperswitch_masking::Secret;
+
+    fn card() -> CardDetails {
+        CardDetails {
+            card_number: Secret::new("4111111111111111".to_string()),
+            card_exp_month: Some(Secret::new("03".to_string())),
+            card_exp_year: Some(Secret::new("2099".to_string())),
+            card_cvc: Some(Secret::new("737".to_string())),
+            card_holder_name: None,
+            card_issuer: None,
+            card_network: None,
+            card_type: None,
+            card_issuing_country: None,
+            nick_name: None,
+            co_badged_card_data: None,
+        }
+    }
+
+    async fn authorize_error_sub_code(card: CardDetails) -> String {
+        let payload = PaymentServiceAuthorizeRequest {
+            request_ref_id: "pay_123".to_string(),
+            amount: 1000,
+            minor_amount: 1000,
+            currency: "USD".to_string(),
+            payment_method: PaymentMethod::Card(card),
+            return_url: Some("https://example.com/return".to_string()),
+            customer_id: None,
+            capture_method: None,
+            setup_future_usage: None,
+            off_session: None,
+            browser_info: None,
+            billing_address: None,
+        };
+        let connector_auth_type = ConnectorAuthType::BodyKey {
+            api_key: Secret::new("api_key".to_string()),
+            key1: Secret::new("TestMerchant".to_string()),
+        };
+        let payment = Payment::new(Connectors::default(), Proxy::default());
+        let error = payment_authorize(&payment, ConnectorEnum::Adyen, connector_auth_type, payload)
+            .await
+            .expect_err("invalid card data should be rejected before the connector call");
+        error.current_context().get_api_error().sub_code.clone()
+    }
+
+    #[tokio::test]
+    async fn returns_the_card_validation_sub_code() {
+        let cases = [
+            (
+                CardDetails {
+                    card_exp_month: None,
+                    ..card()
+                },
+                "MISSING_EXP_MONTH",
+            ),
+            (
+                CardDetails {
+                    card_exp_year: Some(Secret::new("2001".to_string())),
+                    ..card()
+                },
+                "CARD_EXPIRED",
+            ),
+            (
+                CardDetails {
+                    card_number: Secret::new("4111111111111112".to_string()),
+                    ..card()
+                },
+                "INVALID_CARD_NUMBER",
+            ),
+            (
+                CardDetails {
+                    card_cvc: Some(Secret::new("12".to_string())),
+                    ..card()
+                },
+                "INVALID_CVC",
+            ),
+        ];
+        for (card, expected) in cases {
+            assert_eq!(authorize_error_sub_code(card).await, expected);
+        }
+    }
+
+    #[tokio::test]
+    async fn rejects_an_adyen_sync_without_the_redirect_result() {
+        let payload = PaymentServiceGetRequest {
+            request_ref_id: "pay_123".to_string(),
+            connector_transaction_id: "8815161318854998".to_string(),
+            encoded_data: None,
+        };
+        let connector_auth_type = ConnectorAuthType::BodyKey {
+            api_key: Secret::new("api_key".to_string()),
+            key1: Secret::new("TestMerchant".to_string()),
+        };
+        let payment = Payment::new(Connectors::default(), Proxy::default());
+        let error = payment_sync(&payment, ConnectorEnum::Adyen, connector_auth_type, payload)
+            .await
+            .expect_err("the sync request can't be built without encoded_data");
+        let api_error = error.current_context().get_api_error();
+        assert_eq!(api_error.sub_code, "MISSING_REQUIRED_FIELD");
+        assert_eq!(api_error.error_identifier, 400);
+    }
 }
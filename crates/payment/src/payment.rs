@@ -1,23 +1,30 @@
 use std::fmt::Debug;
 
 // use super::macros;
+use common_enums::ApiClientError;
 use common_utils::errors::CustomResult;
 use connector_integration::types::ConnectorData;
 use domain_types::{
-    connector_flow::Authorize,
+    connector_flow::{Authorize, Capture, PSync, RSync, Refund, Void},
     connector_types::{
-        ConnectorEnum, PaymentFlowData, PaymentsAuthorizeData, PaymentsResponseData,
+        ConnectorEnum, PaymentFlowData, PaymentVoidData, PaymentsAuthorizeData,
+        PaymentsCaptureData, PaymentsResponseData, PaymentsSyncData, RefundFlowData,
+        RefundSyncData, RefundsData, RefundsResponseData,
     },
     errors::ConnectorError,
-    payment_method::{DefaultPCIHolder, PaymentMethodDataTypes},
+    payment_method::{DefaultPCIHolder, PaymentMethodDataTypes, VaultTokenHolder},
     router_data::ErrorResponse,
     router_data_v2::{ConnectorAuthType, RouterDataV2},
-    types::{ConnectorParams, Connectors},
-    utils::ForeignTryFrom,
+    types::{Connectors, Proxy},
+    utils::{ForeignFrom, ForeignTryFrom},
 };
 use grpc::errors::PaymentAuthorizationError;
 use grpc::payments::PaymentStatus;
-use grpc::types::PaymentServiceAuthorizeRequest;
+use grpc::types::{
+    PaymentMethod, PaymentServiceAuthorizeRequest, PaymentServiceAuthorizeResponse,
+    PaymentServiceCaptureRequest, PaymentServiceGetRequest, PaymentServiceGetResponse,
+    PaymentServiceRefundRequest, PaymentServiceVoidRequest, RefundServiceGetRequest,
+};
 
 use interfaces::connector_integration_v2::BoxedConnectorIntegrationV2;
 
@@ -30,7 +37,72 @@ pub struct IntOutput {
     amount: i64,
 }
 
-pub struct Payment;
+#[derive(Clone, Debug)]
+pub struct Payment {
+    pub connectors: Connectors,
+    pub proxy: Proxy,
+}
+
+impl Payment {
+    pub fn new(connectors: Connectors, proxy: Proxy) -> Self {
+        Self { connectors, proxy }
+    }
+}
+
+/// Turns a failed connector processing step into the error returned for the flow. The full
+/// report is logged, the response carries the connector error and the client error behind it.
+///
+/// The payment is only left pending when the connector may have acted on the request, i.e. the
+/// call timed out or the connection dropped before the response arrived. Fields the connector
+/// needs but the request lacks are reported as a bad request.
+fn connector_step_error(
+    err: error_stack::Report<ConnectorError>,
+    error_code: &str,
+) -> PaymentAuthorizationError {
+    tracing::error!(error = ?err, error_code, "connector processing step failed");
+    let context = err.current_context();
+    if matches!(
+        context,
+        ConnectorError::NotImplemented(_) | ConnectorError::FlowNotSupported { .. }
+    ) {
+        return PaymentAuthorizationError::new(
+            PaymentStatus::Failure.to_string(),
+            Some(context.to_string()),
+            Some("NOT_IMPLEMENTED".to_string()),
+            Some(501),
+        );
+    }
+    if matches!(
+        context,
+        ConnectorError::MissingRequiredField { .. } | ConnectorError::MissingRequiredFields { .. }
+    ) {
+        return PaymentAuthorizationError::new(
+            PaymentStatus::Failure.to_string(),
+            Some(context.to_string()),
+            Some("MISSING_REQUIRED_FIELD".to_string()),
+            Some(400),
+        );
+    }
+    let api_client_error = err.downcast_ref::<ApiClientError>();
+    let status = match api_client_error {
+        Some(
+            ApiClientError::RequestTimeoutReceived
+            | ApiClientError::ConnectionClosedIncompleteMessage
+            | ApiClientError::GatewayTimeoutReceived,
+        ) => PaymentStatus::Pending,
+        _ => PaymentStatus::Failure,
+    };
+    let error_message = match api_client_error {
+        Some(api_client_error) => format!("{context}: {api_client_error}"),
+        None => context.to_string(),
+    };
+    PaymentAuthorizationError::new(
+        status.to_string(),
+        Some(error_message),
+        Some(error_code.to_string()),
+        None,
+    )
+}
 
 pub trait PaymentOperationInternal {
     async fn internal_void(&self) -> IntOutput;
@@ -41,8 +113,58 @@ pub trait PaymentService {
     async fn authorize(
         &self,
         payload: PaymentServiceAuthorizeRequest,
-    ) -> Result<(), PaymentAuthorizationError>;
+        connector: ConnectorEnum,
+        connector_auth_type: ConnectorAuthType,
+    ) -> Result<PaymentServiceAuthorizeResponse, PaymentAuthorizationError>;
+
+    async fn sync(
+        &self,
+        payload: PaymentServiceGetRequest,
+        connector: ConnectorEnum,
+        connector_auth_type: ConnectorAuthType,
+    ) -> Result<PaymentServiceGetResponse, PaymentAuthorizationError>;
+
+    async fn capture(
+        &self,
+        payload: PaymentServiceCaptureRequest,
+        connector: ConnectorEnum,
+        connector_auth_type: ConnectorAuthType,
+    ) -> Result<
+        RouterDataV2<Capture, PaymentFlowData, PaymentsCaptureData, PaymentsResponseData>,
+        PaymentAuthorizationError,
+    >;
+
+    async fn void(
+        &self,
+        payload: PaymentServiceVoidRequest,
+        connector: ConnectorEnum,
+        connector_auth_type: ConnectorAuthType,
+    ) -> Result<
+        RouterDataV2<Void, PaymentFlowData, PaymentVoidData, PaymentsResponseData>,
+        PaymentAuthorizationError,
+    >;
+
+    async fn refund(
+        &self,
+        payload: PaymentServiceRefundRequest,
+        connector: ConnectorEnum,
+        connector_auth_type: ConnectorAuthType,
+    ) -> Result<
+        RouterDataV2<Refund, RefundFlowData, RefundsData, RefundsResponseData>,
+        PaymentAuthorizationError,
+    >;
+
+    async fn refund_sync(
+        &self,
+        payload: RefundServiceGetRequest,
+        connector: ConnectorEnum,
+        connector_auth_type: ConnectorAuthType,
+    ) -> Result<
+        RouterDataV2<RSync, RefundFlowData, RefundSyncData, RefundsResponseData>,
+        PaymentAuthorizationError,
+    >;
 }
+
 impl Payment {
     #[allow(clippy::too_many_arguments)]
     async fn process_authorization_internal<
@@ -61,7 +183,11 @@ impl Payment {
         &self,
         payload: PaymentServiceAuthorizeRequest, // grpc Request
         connector: ConnectorEnum,
-    ) -> Result<(), PaymentAuthorizationError> {
+        connector_auth_type: ConnectorAuthType,
+    ) -> Result<
+        RouterDataV2<Authorize, PaymentFlowData, PaymentsAuthorizeData<T>, PaymentsResponseData>,
+        PaymentAuthorizationError,
+    > {
         let connector_data: ConnectorData<T> = ConnectorData::get_connector_by_name(&connector);
         // let url: CustomResult<String, ConnectorError> = connector_data.connector.get_url();
         let connector_integration: BoxedConnectorIntegrationV2<
@@ -72,39 +198,15 @@ impl Payment {
             PaymentsResponseData,
         > = connector_data.connector.get_connector_integration_v2();
         // let _ = connector_integration.get_url();
-        let base_url = "https://apitest.cybersource.com/pts/v2/payments/".to_string();
-        let connector_params = ConnectorParams::new(base_url);
-
-        let connector = Connectors {
-            adyen: connector_params,
-        };
+        let connector = self.connectors.clone();
         let metadata = "metadata".to_string();
 
         let payment_flow_data =
-            PaymentFlowData::foreign_try_from((payload.clone(), connector, &metadata)).map_err(
-                |err| {
-                    PaymentAuthorizationError::new(
-                        PaymentStatus::Pending.to_string(),
-                        Some("Failed to process payment flow data".to_string()),
-                        Some("PAYMENT_FLOW_ERROR".to_string()),
-                        None,
-                    )
-                },
-            )?;
+            PaymentFlowData::foreign_try_from((payload.clone(), connector, &metadata))
+                .map_err(|err| PaymentAuthorizationError::from(err.current_context().clone()))?;
 
         let payment_authorize_data = PaymentsAuthorizeData::<T>::foreign_try_from(payload.clone())
-            .map_err(|err| {
-                PaymentAuthorizationError::new(
-                    PaymentStatus::Pending.to_string(),
-                    Some("Failed to process payment authorize data".to_string()),
-                    Some("PAYMENT_AUTHORIZE_DATA_ERROR".to_string()),
-                    None,
-                )
-            })?;
-
-        let auth = ConnectorAuthType::HeaderKey {
-            api_key: "api_key".to_string(),
-        };
+            .map_err(|err| PaymentAuthorizationError::from(err.current_context().clone()))?;
 
         let router_data = RouterDataV2::<
             Authorize,
@@ -114,38 +216,345 @@ impl Payment {
         > {
             flow: std::marker::PhantomData,
             resource_common_data: payment_flow_data.clone(),
-            connector_auth_type: auth.clone(),
+            connector_auth_type,
             request: payment_authorize_data,
             response: Err(ErrorResponse::default()),
         };
-        let response = external_services::service::execute_connector_processing_step(
-            // &config.proxy,
+        external_services::service::execute_connector_processing_step(
+            &self.proxy,
             connector_integration,
             router_data,
             None,
             // event_params,
             // token_data,
             common_enums::CallConnectorAction::Trigger,
+            external_services::service::RetryMode::ConnectionErrors,
             // test_context,
             // api_tag,
         )
-        .await;
-        Ok(())
-        // let _ = connector_data.connector.test();
+        .await
+        .map_err(|err| connector_step_error(err, "PAYMENT_AUTHORIZE_ERROR"))
+    }
+
+    async fn process_sync_internal(
+        &self,
+        payload: PaymentServiceGetRequest,
+        connector: ConnectorEnum,
+        connector_auth_type: ConnectorAuthType,
+    ) -> Result<
+        RouterDataV2<PSync, PaymentFlowData, PaymentsSyncData, PaymentsResponseData>,
+        PaymentAuthorizationError,
+    > {
+        let connector_data: ConnectorData<DefaultPCIHolder> =
+            ConnectorData::get_connector_by_name(&connector);
+        let connector_integration: BoxedConnectorIntegrationV2<
+            '_,
+            PSync,
+            PaymentFlowData,
+            PaymentsSyncData,
+            PaymentsResponseData,
+        > = connector_data.connector.get_connector_integration_v2();
+
+        let payment_flow_data =
+            PaymentFlowData::foreign_try_from((payload.clone(), self.connectors.clone()))
+                .map_err(|err| PaymentAuthorizationError::from(err.current_context().clone()))?;
+
+        let payment_sync_data = PaymentsSyncData::foreign_try_from(payload)
+            .map_err(|err| PaymentAuthorizationError::from(err.current_context().clone()))?;
+
+        let router_data =
+            RouterDataV2::<PSync, PaymentFlowData, PaymentsSyncData, PaymentsResponseData> {
+                flow: std::marker::PhantomData,
+                resource_common_data: payment_flow_data,
+                connector_auth_type,
+                request: payment_sync_data,
+                response: Err(ErrorResponse::default()),
+            };
+        external_services::service::execute_connector_processing_step(
+            &self.proxy,
+            connector_integration,
+            router_data,
+            None,
+            common_enums::CallConnectorAction::Trigger,
+            external_services::service::RetryMode::TransientErrors,
+        )
+        .await
+        .map_err(|err| connector_step_error(err, "PAYMENT_SYNC_ERROR"))
+    }
+
+    async fn process_capture_internal(
+        &self,
+        payload: PaymentServiceCaptureRequest,
+        connector: ConnectorEnum,
+        connector_auth_type: ConnectorAuthType,
+    ) -> Result<
+        RouterDataV2<Capture, PaymentFlowData, PaymentsCaptureData, PaymentsResponseData>,
+        PaymentAuthorizationError,
+    > {
+        let connector_data: ConnectorData<DefaultPCIHolder> =
+            ConnectorData::get_connector_by_name(&connector);
+        let connector_integration: BoxedConnectorIntegrationV2<
+            '_,
+            Capture,
+            PaymentFlowData,
+            PaymentsCaptureData,
+            PaymentsResponseData,
+        > = connector_data.connector.get_connector_integration_v2();
+
+        let payment_flow_data =
+            PaymentFlowData::foreign_try_from((payload.clone(), self.connectors.clone()))
+                .map_err(|err| PaymentAuthorizationError::from(err.current_context().clone()))?;
+
+        let payment_capture_data = PaymentsCaptureData::foreign_try_from(payload)
+            .map_err(|err| PaymentAuthorizationError::from(err.current_context().clone()))?;
+
+        let router_data =
+            RouterDataV2::<Capture, PaymentFlowData, PaymentsCaptureData, PaymentsResponseData> {
+                flow: std::marker::PhantomData,
+                resource_common_data: payment_flow_data,
+                connector_auth_type,
+                request: payment_capture_data,
+                response: Err(ErrorResponse::default()),
+            };
+        external_services::service::execute_connector_processing_step(
+            &self.proxy,
+            connector_integration,
+            router_data,
+            None,
+            common_enums::CallConnectorAction::Trigger,
+            external_services::service::RetryMode::ConnectionErrors,
+        )
+        .await
+        .map_err(|err| connector_step_error(err, "PAYMENT_CAPTURE_ERROR"))
+    }
+
+    async fn process_void_internal(
+        &self,
+        payload: PaymentServiceVoidRequest,
+        connector: ConnectorEnum,
+        connector_auth_type: ConnectorAuthType,
+    ) -> Result<
+        RouterDataV2<Void, PaymentFlowData, PaymentVoidData, PaymentsResponseData>,
+        PaymentAuthorizationError,
+    > {
+        let connector_data: ConnectorData<DefaultPCIHolder> =
+            ConnectorData::get_connector_by_name(&connector);
+        let connector_integration: BoxedConnectorIntegrationV2<
+            '_,
+            Void,
+            PaymentFlowData,
+            PaymentVoidData,
+            PaymentsResponseData,
+        > = connector_data.connector.get_connector_integration_v2();
+
+        let payment_flow_data =
+            PaymentFlowData::foreign_try_from((payload.clone(), self.connectors.clone()))
+                .map_err(|err| PaymentAuthorizationError::from(err.current_context().clone()))?;
+
+        let payment_void_data = PaymentVoidData::foreign_try_from(payload)
+            .map_err(|err| PaymentAuthorizationError::from(err.current_context().clone()))?;
+
+        let router_data =
+            RouterDataV2::<Void, PaymentFlowData, PaymentVoidData, PaymentsResponseData> {
+                flow: std::marker::PhantomData,
+                resource_common_data: payment_flow_data,
+                connector_auth_type,
+                request: payment_void_data,
+                response: Err(ErrorResponse::default()),
+            };
+        external_services::service::execute_connector_processing_step(
+            &self.proxy,
+            connector_integration,
+            router_data,
+            None,
+            common_enums::CallConnectorAction::Trigger,
+            external_services::service::RetryMode::ConnectionErrors,
+        )
+        .await
+        .map_err(|err| connector_step_error(err, "PAYMENT_VOID_ERROR"))
+    }
+
+    async fn process_refund_internal(
+        &self,
+        payload: PaymentServiceRefundRequest,
+        connector: ConnectorEnum,
+        connector_auth_type: ConnectorAuthType,
+    ) -> Result<
+        RouterDataV2<Refund, RefundFlowData, RefundsData, RefundsResponseData>,
+        PaymentAuthorizationError,
+    > {
+        let connector_data: ConnectorData<DefaultPCIHolder> =
+            ConnectorData::get_connector_by_name(&connector);
+        let connector_integration: BoxedConnectorIntegrationV2<
+            '_,
+            Refund,
+            RefundFlowData,
+            RefundsData,
+            RefundsResponseData,
+        > = connector_data.connector.get_connector_integration_v2();
+
+        let refund_flow_data =
+            RefundFlowData::foreign_try_from((payload.clone(), self.connectors.clone()))
+                .map_err(|err| PaymentAuthorizationError::from(err.current_context().clone()))?;
+
+        let refunds_data = RefundsData::foreign_try_from(payload)
+            .map_err(|err| PaymentAuthorizationError::from(err.current_context().clone()))?;
+
+        let router_data = RouterDataV2::<Refund, RefundFlowData, RefundsData, RefundsResponseData> {
+            flow: std::marker::PhantomData,
+            resource_common_data: refund_flow_data,
+            connector_auth_type,
+            request: refunds_data,
+            response: Err(ErrorResponse::default()),
+        };
+        external_services::service::execute_connector_processing_step(
+            &self.proxy,
+            connector_integration,
+            router_data,
+            None,
+            common_enums::CallConnectorAction::Trigger,
+            external_services::service::RetryMode::ConnectionErrors,
+        )
+        .await
+        .map_err(|err| connector_step_error(err, "REFUND_ERROR"))
+    }
+
+    async fn process_refund_sync_internal(
+        &self,
+        payload: RefundServiceGetRequest,
+        connector: ConnectorEnum,
+        connector_auth_type: ConnectorAuthType,
+    ) -> Result<
+        RouterDataV2<RSync, RefundFlowData, RefundSyncData, RefundsResponseData>,
+        PaymentAuthorizationError,
+    > {
+        let connector_data: ConnectorData<DefaultPCIHolder> =
+            ConnectorData::get_connector_by_name(&connector);
+        let connector_integration: BoxedConnectorIntegrationV2<
+            '_,
+            RSync,
+            RefundFlowData,
+            RefundSyncData,
+            RefundsResponseData,
+        > = connector_data.connector.get_connector_integration_v2();
+
+        let refund_flow_data =
+            RefundFlowData::foreign_try_from((payload.clone(), self.connectors.clone()))
+                .map_err(|err| PaymentAuthorizationError::from(err.current_context().clone()))?;
+
+        let refund_sync_data = RefundSyncData::foreign_try_from(payload)
+            .map_err(|err| PaymentAuthorizationError::from(err.current_context().clone()))?;
+
+        let router_data =
+            RouterDataV2::<RSync, RefundFlowData, RefundSyncData, RefundsResponseData> {
+                flow: std::marker::PhantomData,
+                resource_common_data: refund_flow_data,
+                connector_auth_type,
+                request: refund_sync_data,
+                response: Err(ErrorResponse::default()),
+            };
+        external_services::service::execute_connector_processing_step(
+            &self.proxy,
+            connector_integration,
+            router_data,
+            None,
+            common_enums::CallConnectorAction::Trigger,
+            external_services::service::RetryMode::TransientErrors,
+        )
+        .await
+        .map_err(|err| connector_step_error(err, "REFUND_SYNC_ERROR"))
     }
 }
 
 impl PaymentService for Payment {
+    /// Raw card numbers are handled by `DefaultPCIHolder`, vault tokens by `VaultTokenHolder`
     async fn authorize(
         &self,
         payload: PaymentServiceAuthorizeRequest,
-    ) -> Result<(), PaymentAuthorizationError> {
-        // println!("Payload {:?}", payload);
-        let connector = ConnectorEnum::Adyen;
-        let _ = self
-            .process_authorization_internal::<DefaultPCIHolder>(payload, connector)
-            .await;
-        Ok(())
+        connector: ConnectorEnum,
+        connector_auth_type: ConnectorAuthType,
+    ) -> Result<PaymentServiceAuthorizeResponse, PaymentAuthorizationError> {
+        match payload.payment_method {
+            PaymentMethod::Card(_) => self
+                .process_authorization_internal::<DefaultPCIHolder>(
+                    payload,
+                    connector,
+                    connector_auth_type,
+                )
+                .await
+                .map(PaymentServiceAuthorizeResponse::foreign_from),
+            PaymentMethod::CardProxy(_) => self
+                .process_authorization_internal::<VaultTokenHolder>(
+                    payload,
+                    connector,
+                    connector_auth_type,
+                )
+                .await
+                .map(PaymentServiceAuthorizeResponse::foreign_from),
+        }
+    }
+
+    async fn sync(
+        &self,
+        payload: PaymentServiceGetRequest,
+        connector: ConnectorEnum,
+        connector_auth_type: ConnectorAuthType,
+    ) -> Result<PaymentServiceGetResponse, PaymentAuthorizationError> {
+        self.process_sync_internal(payload, connector, connector_auth_type)
+            .await
+            .map(PaymentServiceGetResponse::foreign_from)
+    }
+
+    async fn capture(
+        &self,
+        payload: PaymentServiceCaptureRequest,
+        connector: ConnectorEnum,
+        connector_auth_type: ConnectorAuthType,
+    ) -> Result<
+        RouterDataV2<Capture, PaymentFlowData, PaymentsCaptureData, PaymentsResponseData>,
+        PaymentAuthorizationError,
+    > {
+        self.process_capture_internal(payload, connector, connector_auth_type)
+            .await
+    }
+
+    async fn void(
+        &self,
+        payload: PaymentServiceVoidRequest,
+        connector: ConnectorEnum,
+        connector_auth_type: ConnectorAuthType,
+    ) -> Result<
+        RouterDataV2<Void, PaymentFlowData, PaymentVoidData, PaymentsResponseData>,
+        PaymentAuthorizationError,
+    > {
+        self.process_void_internal(payload, connector, connector_auth_type)
+            .await
+    }
+
+    async fn refund(
+        &self,
+        payload: PaymentServiceRefundRequest,
+        connector: ConnectorEnum,
+        connector_auth_type: ConnectorAuthType,
+    ) -> Result<
+        RouterDataV2<Refund, RefundFlowData, RefundsData, RefundsResponseData>,
+        PaymentAuthorizationError,
+    > {
+        self.process_refund_internal(payload, connector, connector_auth_type)
+            .await
+    }
+
+    async fn refund_sync(
+        &self,
+        payload: RefundServiceGetRequest,
+        connector: ConnectorEnum,
+        connector_auth_type: ConnectorAuthType,
+    ) -> Result<
+        RouterDataV2<RSync, RefundFlowData, RefundSyncData, RefundsResponseData>,
+        PaymentAuthorizationError,
+    > {
+        self.process_refund_sync_internal(payload, connector, connector_auth_type)
+            .await
     }
 }
 